@@ -0,0 +1,6 @@
+/// Trait for copying text to the system clipboard
+pub trait Clipboard {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    fn copy(&self, text: &str) -> Result<(), Self::Error>;
+}
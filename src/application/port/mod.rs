@@ -1,3 +1,7 @@
+mod clipboard;
 mod diff_formatter;
+mod session_store;
 
+pub use clipboard::Clipboard;
 pub use diff_formatter::DiffFormatter;
+pub use session_store::SessionStore;
@@ -0,0 +1,24 @@
+use std::path::Path;
+
+use crate::domain::{Mark, Session};
+
+/// Persistence for saved sessions and marks of one repository
+pub trait SessionStore {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// The session started from `file`, if one was saved
+    fn load(&self, file: &Path) -> Result<Option<Session>, Self::Error>;
+
+    /// Save a session, replacing any earlier one for the same file
+    fn save(&self, session: &Session) -> Result<(), Self::Error>;
+
+    /// All saved sessions, most recently saved first
+    fn list(&self) -> Result<Vec<Session>, Self::Error>;
+
+    fn delete(&self, file: &Path) -> Result<(), Self::Error>;
+
+    /// Marks set in this repository, sorted by name
+    fn load_marks(&self) -> Result<Vec<Mark>, Self::Error>;
+
+    fn save_marks(&self, marks: &[Mark]) -> Result<(), Self::Error>;
+}
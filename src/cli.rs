@@ -0,0 +1,296 @@
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+
+use crate::domain::date;
+use crate::domain::{CommitHash, HistoryBoundary};
+use crate::print::OutputFormat;
+
+pub const USAGE: &str = "Usage: blake [--since <date>] [--resume] [--print | --format <plain|json|porcelain>] \
+[-L <start>,<end>] [--drill <line>[,<line>...]] [<rev> | <rev>..<rev>] <file>";
+
+/// Parsed command line arguments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cli {
+    pub file_path: PathBuf,
+    /// Revision the first blame frame is built at
+    pub revision: CommitHash,
+    pub boundary: Option<HistoryBoundary>,
+    /// Print the blame to stdout instead of starting the TUI
+    pub print: Option<OutputFormat>,
+    /// 1-based inclusive line range to print
+    pub line_range: Option<(usize, usize)>,
+    /// Line numbers to drill down through before printing, one per frame
+    pub drill: Vec<usize>,
+    /// Restore the session saved for this file
+    pub resume: bool,
+}
+
+impl Cli {
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        Self::parse_at(args, date::now())
+    }
+
+    fn parse_at(args: &[String], now: i64) -> Result<Self, String> {
+        let mut since = None;
+        let mut print = None;
+        let mut line_range = None;
+        let mut drill = Vec::new();
+        let mut resume = false;
+        let mut positional = Vec::new();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            if arg == "--since" {
+                let value = iter.next().ok_or("--since requires a date")?;
+                since = Some(parse_since(value, now)?);
+            } else if let Some(value) = arg.strip_prefix("--since=") {
+                since = Some(parse_since(value, now)?);
+            } else if arg == "--resume" {
+                resume = true;
+            } else if arg == "--print" {
+                print = print.or(Some(OutputFormat::Plain));
+            } else if arg == "--format" {
+                let value = iter.next().ok_or("--format requires a format")?;
+                print = Some(value.parse()?);
+            } else if let Some(value) = arg.strip_prefix("--format=") {
+                print = Some(value.parse()?);
+            } else if arg == "-L" {
+                let value = iter.next().ok_or("-L requires <start>,<end>")?;
+                line_range = Some(parse_line_range(value)?);
+            } else if let Some(value) = arg.strip_prefix("-L") {
+                line_range = Some(parse_line_range(value)?);
+            } else if arg == "--drill" {
+                let value = iter.next().ok_or("--drill requires line numbers")?;
+                drill = parse_drill(value)?;
+            } else if let Some(value) = arg.strip_prefix("--drill=") {
+                drill = parse_drill(value)?;
+            } else if arg.starts_with("--") {
+                return Err(format!("Unknown option: {}", arg));
+            } else {
+                positional.push(arg.as_str());
+            }
+        }
+
+        let (rev, file) = match positional.as_slice() {
+            [file] => (None, *file),
+            [rev, file] => (Some(*rev), *file),
+            _ => return Err(USAGE.to_string()),
+        };
+
+        let (revision, range_start) = match rev {
+            None => (CommitHash::head(), None),
+            Some(rev) => match rev.split_once("..") {
+                Some(("", _)) => {
+                    return Err(format!("Range is missing its oldest commit: {}", rev));
+                }
+                Some((oldest, "")) => (CommitHash::head(), Some(CommitHash::from(oldest))),
+                Some((oldest, newest)) => {
+                    (CommitHash::from(newest), Some(CommitHash::from(oldest)))
+                }
+                None => (CommitHash::from(rev), None),
+            },
+        };
+
+        let boundary = match (range_start, since) {
+            (Some(_), Some(_)) => return Err("--since cannot be combined with a range".to_string()),
+            (Some(oldest), None) => Some(HistoryBoundary::Commit(oldest)),
+            (None, Some(since)) => Some(HistoryBoundary::Since(since)),
+            (None, None) => None,
+        };
+
+        if print.is_none() && (line_range.is_some() || !drill.is_empty()) {
+            return Err("-L and --drill require --print or --format".to_string());
+        }
+
+        Ok(Self {
+            file_path: PathBuf::from(file),
+            revision,
+            boundary,
+            print,
+            line_range,
+            drill,
+            resume,
+        })
+    }
+}
+
+/// Parse `YYYY-MM-DD`, `<n>.<unit>` or `<n> <unit> ago` into a unix timestamp
+fn parse_since(value: &str, now: i64) -> Result<i64, String> {
+    let invalid = || format!("Invalid date: {}", value);
+
+    if let Some(timestamp) = parse_iso_date(value) {
+        return Ok(timestamp);
+    }
+
+    let relative = value.trim().trim_end_matches("ago").trim();
+    let (amount, unit) = relative.split_once(['.', ' ']).ok_or_else(invalid)?;
+    let amount: u32 = amount.parse().map_err(|_| invalid())?;
+
+    let unit_secs = match unit.trim().trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 3600,
+        "day" => 86400,
+        "week" => 7 * 86400,
+        "month" => 30 * 86400,
+        "year" => 365 * 86400,
+        _ => return Err(invalid()),
+    };
+
+    i64::from(amount)
+        .checked_mul(unit_secs)
+        .and_then(|secs| now.checked_sub(secs))
+        .ok_or_else(invalid)
+}
+
+/// Parse git's `-L <start>,<end>` or `-L <start>,+<count>`; a missing end means end of file
+fn parse_line_range(value: &str) -> Result<(usize, usize), String> {
+    let invalid = || format!("Invalid line range: {}", value);
+    let line = |s: &str| {
+        s.parse::<usize>()
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or_else(invalid)
+    };
+
+    let (start, end) = value.split_once(',').unwrap_or((value, ""));
+    let start = line(start)?;
+    let end = if end.is_empty() {
+        usize::MAX
+    } else if let Some(count) = end.strip_prefix('+') {
+        start.checked_add(line(count)? - 1).ok_or_else(invalid)?
+    } else {
+        line(end)?
+    };
+
+    if end < start {
+        return Err(invalid());
+    }
+    Ok((start, end))
+}
+
+/// Parse a comma-separated list of line numbers
+fn parse_drill(value: &str) -> Result<Vec<usize>, String> {
+    value
+        .split(',')
+        .map(|line| {
+            line.trim()
+                .parse::<usize>()
+                .ok()
+                .filter(|&n| n > 0)
+                .ok_or_else(|| format!("Invalid line number: {}", line))
+        })
+        .collect()
+}
+
+fn parse_iso_date(value: &str) -> Option<i64> {
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+    Some(date.and_hms_opt(0, 0, 0)?.and_utc().timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn parse(args: &[&str]) -> Result<Cli, String> {
+        let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        Cli::parse_at(&args, NOW)
+    }
+
+    #[test]
+    fn file_only_blames_head_without_boundary() {
+        let cli = parse(&["src/main.rs"]).unwrap();
+        assert_eq!(cli.file_path, PathBuf::from("src/main.rs"));
+        assert_eq!(cli.revision, CommitHash::head());
+        assert_eq!(cli.boundary, None);
+    }
+
+    #[test]
+    fn range_sets_revision_and_boundary() {
+        let cli = parse(&["v1.0..v2.0", "a.rs"]).unwrap();
+        assert_eq!(cli.revision, CommitHash::from("v2.0"));
+        assert_eq!(
+            cli.boundary,
+            Some(HistoryBoundary::Commit(CommitHash::from("v1.0")))
+        );
+    }
+
+    #[test]
+    fn open_range_ends_at_head() {
+        let cli = parse(&["v1.0..", "a.rs"]).unwrap();
+        assert_eq!(cli.revision, CommitHash::head());
+        assert_eq!(
+            cli.boundary,
+            Some(HistoryBoundary::Commit(CommitHash::from("v1.0")))
+        );
+    }
+
+    #[test]
+    fn since_accepts_iso_date() {
+        let cli = parse(&["--since", "2020-01-01", "a.rs"]).unwrap();
+        assert_eq!(cli.boundary, Some(HistoryBoundary::Since(1_577_836_800)));
+    }
+
+    #[test]
+    fn since_accepts_relative_dates() {
+        let cli = parse(&["--since=2.weeks", "a.rs"]).unwrap();
+        assert_eq!(cli.boundary, Some(HistoryBoundary::Since(NOW - 14 * 86400)));
+
+        let cli = parse(&["--since", "3 days ago", "a.rs"]).unwrap();
+        assert_eq!(cli.boundary, Some(HistoryBoundary::Since(NOW - 3 * 86400)));
+    }
+
+    #[test]
+    fn since_rejects_negative_and_overflowing_amounts() {
+        assert!(parse(&["--since", "-3.days", "a.rs"]).is_err());
+        assert!(parse(&["--since", "99999999999999.years", "a.rs"]).is_err());
+    }
+
+    #[test]
+    fn since_and_range_are_exclusive() {
+        assert!(parse(&["--since", "1.year", "a..b", "a.rs"]).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["--since", "yesterday-ish", "a.rs"]).is_err());
+        assert!(parse(&["..b", "a.rs"]).is_err());
+        assert!(parse(&["--bogus", "a.rs"]).is_err());
+    }
+
+    #[test]
+    fn print_options() {
+        let cli = parse(&["--format=json", "-L", "10,+5", "--drill", "12,3", "a.rs"]).unwrap();
+        assert_eq!(cli.print, Some(OutputFormat::Json));
+        assert_eq!(cli.line_range, Some((10, 14)));
+        assert_eq!(cli.drill, vec![12, 3]);
+
+        let cli = parse(&["--print", "-L20,30", "a.rs"]).unwrap();
+        assert_eq!(cli.print, Some(OutputFormat::Plain));
+        assert_eq!(cli.line_range, Some((20, 30)));
+
+        let cli = parse(&["a.rs"]).unwrap();
+        assert_eq!(cli.print, None);
+        assert!(!cli.resume);
+        assert!(parse(&["--resume", "a.rs"]).unwrap().resume);
+    }
+
+    #[test]
+    fn rejects_invalid_print_options() {
+        assert!(parse(&["-L", "5,2", "--print", "a.rs"]).is_err());
+        assert!(parse(&["-L", "0,2", "--print", "a.rs"]).is_err());
+        assert!(parse(&["-L", "18446744073709551615,+2", "--print", "a.rs"]).is_err());
+        assert!(parse(&["--format", "xml", "a.rs"]).is_err());
+        assert!(parse(&["--drill", "4", "a.rs"]).is_err());
+    }
+
+    #[test]
+    fn since_accepts_pre_epoch_dates() {
+        let cli = parse(&["--since", "1969-12-31", "a.rs"]).unwrap();
+        assert_eq!(cli.boundary, Some(HistoryBoundary::Since(-86400)));
+    }
+}
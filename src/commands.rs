@@ -0,0 +1,93 @@
+use std::path::PathBuf;
+
+/// Values for the placeholders of a user command
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandContext {
+    pub commit: String,
+    /// First parent of the commit; empty for root commits
+    pub parent: String,
+    /// Repository-relative path of the blamed file
+    pub file: PathBuf,
+    pub line: usize,
+    pub text: String,
+    pub repo: PathBuf,
+}
+
+/// Fill the `%(name)` placeholders of a command line with shell-quoted values,
+/// leaving unknown placeholders as they are
+pub fn expand(template: &str, context: &CommandContext) -> String {
+    let values = [
+        ("commit", context.commit.clone()),
+        ("parent", context.parent.clone()),
+        ("file", context.file.to_string_lossy().to_string()),
+        ("line", context.line.to_string()),
+        ("text", context.text.clone()),
+        ("repo", context.repo.to_string_lossy().to_string()),
+    ];
+
+    let mut out = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("%(") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let value = after.find(')').and_then(|end| {
+            let name = &after[..end];
+            let (_, value) = values.iter().find(|(n, _)| *n == name)?;
+            Some((end, value))
+        });
+        match value {
+            Some((end, value)) => {
+                out.push_str(&shell_quote(value));
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push_str("%(");
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Quote a value for POSIX shells, leaving simple words bare
+fn shell_quote(value: &str) -> String {
+    let is_plain = |c: char| c.is_ascii_alphanumeric() || "-_./:@%+=,".contains(c);
+    if !value.is_empty() && value.chars().all(is_plain) {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> CommandContext {
+        CommandContext {
+            commit: "abc123".to_string(),
+            parent: String::new(),
+            file: "src/my file.rs".into(),
+            line: 12,
+            text: "let s = 'x';".to_string(),
+            repo: "/repo".into(),
+        }
+    }
+
+    #[test]
+    fn expands_quoted_placeholders() {
+        assert_eq!(
+            expand("git show %(commit) -- %(file):%(line)", &context()),
+            "git show abc123 -- 'src/my file.rs':12"
+        );
+        assert_eq!(
+            expand("echo %(text) %(parent)", &context()),
+            r"echo 'let s = '\''x'\'';' ''"
+        );
+        assert_eq!(
+            expand("echo %(unknown) 100%( %(repo)", &context()),
+            "echo %(unknown) 100%( /repo"
+        );
+    }
+}
@@ -0,0 +1,403 @@
+use serde::{Deserialize, Serialize};
+
+use ratatui::style::Color;
+
+use crate::config::theme::ColorValue;
+use crate::domain::date::{DateFormat, DateStyle};
+
+/// Minimum width kept for the content column before other columns are hidden
+const MIN_CONTENT_WIDTH: u16 = 20;
+
+/// Kinds of column the blame view can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ColumnKind {
+    Hash,
+    Author,
+    AuthorEmail,
+    Date,
+    Age,
+    LineNumber,
+    OriginalLineNumber,
+    OriginalPath,
+    Subject,
+    TrailIndex,
+    Content,
+}
+
+impl ColumnKind {
+    fn default_width(self, date_width: u16) -> u16 {
+        match self {
+            ColumnKind::Hash => 7,
+            ColumnKind::Author => 12,
+            ColumnKind::AuthorEmail => 20,
+            ColumnKind::Date => date_width,
+            ColumnKind::Age => 14,
+            ColumnKind::LineNumber | ColumnKind::OriginalLineNumber => 5,
+            ColumnKind::OriginalPath => 20,
+            ColumnKind::Subject => 30,
+            ColumnKind::TrailIndex => 3,
+            ColumnKind::Content => 0,
+        }
+    }
+
+    fn default_min_width(self, date_width: u16) -> u16 {
+        match self {
+            ColumnKind::Author | ColumnKind::AuthorEmail | ColumnKind::OriginalPath => 6,
+            ColumnKind::Subject => 10,
+            kind => kind.default_width(date_width),
+        }
+    }
+}
+
+/// A column in the blame view
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ColumnConfig {
+    pub kind: ColumnKind,
+    /// Preferred width; the content column always takes the remaining space
+    #[serde(default)]
+    pub width: Option<u16>,
+    /// Width the column may shrink to on narrow terminals
+    #[serde(default)]
+    pub min_width: Option<u16>,
+}
+
+impl ColumnConfig {
+    pub fn new(kind: ColumnKind) -> Self {
+        Self {
+            kind,
+            width: None,
+            min_width: None,
+        }
+    }
+
+    /// Preferred and minimum width
+    fn widths(&self, date_width: u16) -> (u16, u16) {
+        let width = self
+            .width
+            .unwrap_or_else(|| self.kind.default_width(date_width));
+        let min_width = self
+            .min_width
+            .unwrap_or_else(|| self.kind.default_min_width(date_width));
+        (width, min_width.min(width))
+    }
+}
+
+/// How blame lines are coloured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum Coloring {
+    /// Hash column coloured in four age buckets
+    #[default]
+    Age,
+    /// Smooth truecolor gradient by line age
+    Heatmap,
+    /// One colour per author
+    Author,
+}
+
+impl Coloring {
+    /// Next mode when cycling through colourings
+    pub fn next(self) -> Self {
+        match self {
+            Coloring::Age => Coloring::Heatmap,
+            Coloring::Heatmap => Coloring::Author,
+            Coloring::Author => Coloring::Age,
+        }
+    }
+}
+
+/// How consecutive lines from the same blame hunk are grouped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum BlockStyle {
+    /// Metadata on every line
+    #[default]
+    Off,
+    /// Metadata on the first line of each hunk, with a rule between hunks
+    Separator,
+    /// Metadata on the first line of each hunk, alternating the background
+    Alternate,
+}
+
+impl BlockStyle {
+    /// Next style when cycling through block styles
+    pub fn next(self) -> Self {
+        match self {
+            BlockStyle::Off => BlockStyle::Separator,
+            BlockStyle::Separator => BlockStyle::Alternate,
+            BlockStyle::Alternate => BlockStyle::Off,
+        }
+    }
+}
+
+/// What the heatmap measures age against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum HeatmapScale {
+    /// Fixed window from `newest_days` to `oldest_days` before now
+    #[default]
+    Absolute,
+    /// Oldest to newest line in the current frame
+    Relative,
+}
+
+/// Where the heatmap colour is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum HeatmapTarget {
+    /// A one-cell bar at the start of each line
+    #[default]
+    Gutter,
+    /// The background of the whole line
+    Background,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HeatmapConfig {
+    #[serde(default)]
+    pub scale: HeatmapScale,
+    #[serde(default)]
+    pub target: HeatmapTarget,
+    /// Lines younger than this are shown in the hottest colour
+    #[serde(default = "default_newest_days")]
+    pub newest_days: u32,
+    /// Lines older than this are shown in the coldest colour
+    #[serde(default = "default_oldest_days")]
+    pub oldest_days: u32,
+    /// Gradient stops from newest to oldest; `#rrggbb` stops are interpolated
+    #[serde(default = "default_gradient")]
+    pub gradient: Vec<ColorValue>,
+}
+
+fn default_newest_days() -> u32 {
+    7
+}
+
+fn default_oldest_days() -> u32 {
+    730
+}
+
+fn default_gradient() -> Vec<ColorValue> {
+    [
+        Color::Rgb(0xff, 0x4f, 0x1f),
+        Color::Rgb(0xff, 0xc4, 0x3d),
+        Color::Rgb(0x5f, 0x9e, 0xa0),
+        Color::Rgb(0x3a, 0x3f, 0x58),
+    ]
+    .into_iter()
+    .map(ColorValue)
+    .collect()
+}
+
+impl Default for HeatmapConfig {
+    fn default() -> Self {
+        Self {
+            scale: HeatmapScale::default(),
+            target: HeatmapTarget::default(),
+            newest_days: default_newest_days(),
+            oldest_days: default_oldest_days(),
+            gradient: default_gradient(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SyntaxConfig {
+    /// Highlight the content column
+    #[serde(default = "super::default_true")]
+    pub enabled: bool,
+    /// Name of a built-in syntect theme; defaults to one matching the base theme
+    #[serde(default)]
+    pub theme: Option<String>,
+}
+
+impl Default for SyntaxConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            theme: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BlameConfig {
+    /// Columns in display order
+    #[serde(default = "default_columns")]
+    pub columns: Vec<ColumnConfig>,
+    /// Date format in git's `--date` syntax; falls back to git's `blame.date`
+    #[serde(default)]
+    pub date: Option<DateFormat>,
+    /// Colouring used at startup
+    #[serde(default)]
+    pub coloring: Coloring,
+    /// Hunk grouping used at startup
+    #[serde(default)]
+    pub blocks: BlockStyle,
+    #[serde(default)]
+    pub heatmap: HeatmapConfig,
+    #[serde(default)]
+    pub syntax: SyntaxConfig,
+}
+
+fn default_columns() -> Vec<ColumnConfig> {
+    vec![
+        ColumnConfig::new(ColumnKind::Hash),
+        ColumnConfig::new(ColumnKind::Author),
+        ColumnConfig::new(ColumnKind::Date),
+        ColumnConfig::new(ColumnKind::LineNumber),
+        ColumnConfig::new(ColumnKind::Content),
+    ]
+}
+
+impl Default for BlameConfig {
+    fn default() -> Self {
+        Self {
+            columns: default_columns(),
+            date: None,
+            coloring: Coloring::default(),
+            blocks: BlockStyle::default(),
+            heatmap: HeatmapConfig::default(),
+            syntax: SyntaxConfig::default(),
+        }
+    }
+}
+
+impl BlameConfig {
+    pub fn date_format(&self) -> DateFormat {
+        self.date
+            .clone()
+            .unwrap_or_else(|| DateFormat::new(DateStyle::Short))
+    }
+
+    /// Resolve column widths for the available width.
+    ///
+    /// Columns shrink towards their minimum width first; if that is not enough,
+    /// columns are hidden from the right until the content column fits.
+    /// Each column is followed by a one-cell gap, which is not included in the
+    /// returned width.
+    pub fn fit_columns(&self, available: u16) -> Vec<(ColumnKind, u16)> {
+        let date_width = self.date_format().width() as u16;
+        let mut columns: Vec<(ColumnKind, u16, u16)> = self
+            .columns
+            .iter()
+            .filter(|c| c.kind != ColumnKind::Content)
+            .map(|c| {
+                let (width, min_width) = c.widths(date_width);
+                (c.kind, width, min_width)
+            })
+            .collect();
+        let has_content = self.columns.iter().any(|c| c.kind == ColumnKind::Content);
+        let reserved = if has_content { MIN_CONTENT_WIDTH } else { 0 };
+        let budget = available.saturating_sub(reserved);
+
+        let preferred = |columns: &[(ColumnKind, u16, u16)]| -> u16 {
+            columns.iter().map(|(_, width, _)| width + 1).sum()
+        };
+        let minimum = |columns: &[(ColumnKind, u16, u16)]| -> u16 {
+            columns.iter().map(|(_, _, min_width)| min_width + 1).sum()
+        };
+
+        while !columns.is_empty() && minimum(&columns) > budget {
+            columns.pop();
+        }
+
+        // Shrink columns proportionally to their slack until everything fits
+        let slack = preferred(&columns) - minimum(&columns);
+        let excess = preferred(&columns).saturating_sub(budget);
+
+        let mut fitted: Vec<(ColumnKind, u16)> = columns
+            .iter()
+            .map(|&(kind, width, min_width)| {
+                let column_slack = width - min_width;
+                let cut = if slack == 0 {
+                    0
+                } else {
+                    (excess as u32 * column_slack as u32).div_ceil(slack as u32) as u16
+                };
+                (kind, width - cut.min(column_slack))
+            })
+            .collect();
+
+        let used: u16 = fitted.iter().map(|(_, w)| w + 1).sum();
+        if has_content {
+            fitted.push((ColumnKind::Content, available.saturating_sub(used)));
+        }
+
+        // Restore configured order (content may sit anywhere)
+        let mut ordered = Vec::with_capacity(fitted.len());
+        for kind in self.columns.iter().map(|c| c.kind) {
+            if let Some(pos) = fitted.iter().position(|(k, _)| *k == kind) {
+                ordered.push(fitted.remove(pos));
+            }
+        }
+        ordered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn widths(config: &BlameConfig, available: u16) -> Vec<(ColumnKind, u16)> {
+        config.fit_columns(available)
+    }
+
+    #[test]
+    fn wide_terminal_uses_preferred_widths() {
+        let config = BlameConfig::default();
+        assert_eq!(
+            widths(&config, 120),
+            vec![
+                (ColumnKind::Hash, 7),
+                (ColumnKind::Author, 12),
+                (ColumnKind::Date, 10),
+                (ColumnKind::LineNumber, 5),
+                (ColumnKind::Content, 120 - 38),
+            ]
+        );
+    }
+
+    #[test]
+    fn narrow_terminal_shrinks_before_hiding() {
+        let config = BlameConfig::default();
+        let fitted = widths(&config, 54);
+        assert_eq!(fitted.len(), 5);
+        assert!(fitted.contains(&(ColumnKind::Author, 8)));
+        assert!(fitted.contains(&(ColumnKind::Content, 20)));
+    }
+
+    #[test]
+    fn very_narrow_terminal_hides_trailing_columns() {
+        let config = BlameConfig::default();
+        let fitted = widths(&config, 30);
+        assert_eq!(
+            fitted,
+            vec![(ColumnKind::Hash, 7), (ColumnKind::Content, 22)]
+        );
+    }
+
+    #[test]
+    fn date_column_follows_date_format() {
+        let config = BlameConfig {
+            columns: vec![ColumnConfig::new(ColumnKind::Date)],
+            date: Some("iso".parse().unwrap()),
+            ..BlameConfig::default()
+        };
+        assert_eq!(widths(&config, 120), vec![(ColumnKind::Date, 25)]);
+    }
+
+    #[test]
+    fn content_keeps_its_configured_position() {
+        let config = BlameConfig {
+            columns: vec![
+                ColumnConfig::new(ColumnKind::Content),
+                ColumnConfig {
+                    width: Some(4),
+                    ..ColumnConfig::new(ColumnKind::Hash)
+                },
+            ],
+            ..BlameConfig::default()
+        };
+        assert_eq!(
+            widths(&config, 40),
+            vec![(ColumnKind::Content, 35), (ColumnKind::Hash, 4)]
+        );
+    }
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// How yanked text reaches the clipboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum ClipboardMethod {
+    /// The copy command for local sessions when one is set, OSC 52 otherwise
+    #[default]
+    Auto,
+    /// The OSC 52 terminal escape, which also works over SSH and in tmux
+    Osc52,
+    /// The configured copy command
+    Command,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ClipboardConfig {
+    #[serde(default)]
+    pub method: ClipboardMethod,
+    /// Program and arguments that read the text from stdin, e.g. `["wl-copy"]`
+    #[serde(default)]
+    pub command: Option<Vec<String>>,
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Where a user command's output goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum CommandOutput {
+    /// Run without blocking; the status bar reports when it finishes
+    #[default]
+    Background,
+    /// Run without blocking and show its output in the diff pane when it finishes
+    Pager,
+    /// Hand the terminal to the command, for interactive programs
+    Terminal,
+}
+
+/// A shell command bound to keys through `[keymap.commands.<mode>]`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UserCommand {
+    /// Command line run by `sh -c`, with `%(commit)`, `%(parent)`, `%(file)`, `%(line)`,
+    /// `%(text)` and `%(repo)` placeholders
+    pub command: String,
+    #[serde(default)]
+    pub output: CommandOutput,
+    /// Shown in the help view
+    #[serde(default)]
+    pub description: Option<String>,
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::date::{DateFormat, DateStyle};
+
+/// Fields that can appear in the diff view's commit header
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum HeaderField {
+    Commit,
+    Refs,
+    /// Pull or merge request named in the commit message
+    PullRequest,
+    Parents,
+    Author,
+    AuthorDate,
+    Committer,
+    CommitDate,
+    Message,
+    Trailers,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DiffConfig {
+    /// Header fields in display order
+    #[serde(default = "default_header")]
+    pub header: Vec<HeaderField>,
+    /// Date format in git's `--date` syntax
+    #[serde(default = "default_date")]
+    pub date: DateFormat,
+    /// Wrap long lines in the diff pane; toggled with `:set wrap`
+    #[serde(default)]
+    pub wrap: bool,
+}
+
+fn default_date() -> DateFormat {
+    DateFormat::new(DateStyle::Iso)
+}
+
+fn default_header() -> Vec<HeaderField> {
+    vec![
+        HeaderField::Commit,
+        HeaderField::Refs,
+        HeaderField::PullRequest,
+        HeaderField::Parents,
+        HeaderField::Author,
+        HeaderField::AuthorDate,
+        HeaderField::Committer,
+        HeaderField::CommitDate,
+        HeaderField::Message,
+        HeaderField::Trailers,
+    ]
+}
+
+impl Default for DiffConfig {
+    fn default() -> Self {
+        Self {
+            header: default_header(),
+            date: default_date(),
+            wrap: false,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EditorConfig {
+    /// Program and arguments, overriding `$VISUAL` and `$EDITOR`
+    #[serde(default)]
+    pub command: Option<Vec<String>>,
+    /// Arguments passed after the command, with `{file}` and `{line}` placeholders
+    #[serde(default = "default_args")]
+    pub args: Vec<String>,
+}
+
+fn default_args() -> Vec<String> {
+    vec!["+{line}".to_string(), "{file}".to_string()]
+}
+
+impl Default for EditorConfig {
+    fn default() -> Self {
+        Self {
+            command: None,
+            args: default_args(),
+        }
+    }
+}
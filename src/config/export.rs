@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// File format of an exported trail report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum ExportFormat {
+    #[default]
+    Markdown,
+    Json,
+}
+
+impl ExportFormat {
+    /// Format implied by a file extension, if it names one
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_lowercase().as_str() {
+            "md" | "markdown" => Some(Self::Markdown),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Json => "json",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExportConfig {
+    /// Format used when the file name has no recognised extension
+    #[serde(default)]
+    pub format: ExportFormat,
+    /// Include the diff hunk that introduced each tracked line
+    #[serde(default = "super::default_true")]
+    pub include_diff: bool,
+}
+
+impl Default for ExportConfig {
+    fn default() -> Self {
+        Self {
+            format: ExportFormat::default(),
+            include_diff: true,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Code hosting software, which decides the shape of web URLs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ForgeKind {
+    GitHub,
+    GitLab,
+    Bitbucket,
+    /// Gitea and Forgejo, including Codeberg
+    Gitea,
+    Sourcehut,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ForgeConfig {
+    /// Remote to build links from; defaults to `origin`, then the first recognised remote
+    #[serde(default)]
+    pub remote: Option<String>,
+    /// Per-host settings, keyed by the host name in the remote URL
+    #[serde(default)]
+    pub hosts: HashMap<String, ForgeHost>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ForgeHost {
+    /// Forge software on this host, detected from the host name when unset
+    #[serde(default)]
+    pub kind: Option<ForgeKind>,
+    /// Web address of the host when it differs from the remote's, e.g. `https://git.example.com:8443`
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Commit page template with `{base}`, `{host}`, `{repo}` and `{commit}` placeholders
+    #[serde(default)]
+    pub commit: Option<String>,
+    /// File-at-revision template for one line, adding `{path}` and `{start}`
+    #[serde(default)]
+    pub blob: Option<String>,
+    /// File-at-revision template for a line range, adding `{path}`, `{start}` and `{end}`
+    #[serde(default)]
+    pub blob_range: Option<String>,
+    /// Pull or merge request template with a `{number}` placeholder
+    #[serde(default)]
+    pub pull_request: Option<String>,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// An issue tracker whose references appear in commit messages
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IssueTracker {
+    /// Regular expression matching a reference, e.g. `\b[A-Z][A-Z0-9]+-\d+\b`
+    pub pattern: String,
+    /// Issue page template; `{id}` is the whole match and `{1}`, `{2}`... its capture groups
+    pub url: String,
+}
@@ -2,7 +2,10 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::ops::BitOr;
 
-use crate::ui::action::{BlameAction, DiffAction, GlobalAction, HelpAction};
+use crate::ui::action::{
+    BlameAction, DiffAction, GlobalAction, HelpAction, IssuesAction, MarksAction, SessionsAction,
+    StatsAction,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct KeyBinding {
@@ -10,6 +13,10 @@ pub struct KeyBinding {
     pub modifiers: KeyModifiers,
 }
 
+/// Keys pressed one after another, written `g g` or `] c` in the config; most bindings are one key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeySequence(pub Vec<KeyBinding>);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum KeyCode {
     Char(char),
@@ -17,6 +24,10 @@ pub enum KeyCode {
     Escape,
     Backspace,
     Tab,
+    /// Shift+Tab
+    BackTab,
+    Delete,
+    Insert,
     Up,
     Down,
     Left,
@@ -54,6 +65,32 @@ impl KeyBinding {
     }
 }
 
+impl KeySequence {
+    pub fn keys(&self) -> &[KeyBinding] {
+        &self.0
+    }
+
+    /// One plain key per character, e.g. `gg`
+    pub fn chars(keys: &str) -> Self {
+        Self(
+            keys.chars()
+                .map(|c| KeyBinding::new(KeyCode::Char(c)))
+                .collect(),
+        )
+    }
+
+    /// Whether this sequence continues past `keys`
+    pub fn extends(&self, keys: &[KeyBinding]) -> bool {
+        self.0.len() > keys.len() && self.0.starts_with(keys)
+    }
+}
+
+impl From<KeyBinding> for KeySequence {
+    fn from(key: KeyBinding) -> Self {
+        Self(vec![key])
+    }
+}
+
 impl KeyModifiers {
     pub const CTRL: Self = Self {
         ctrl: true,
@@ -100,11 +137,15 @@ fn parse_key_binding(s: &str) -> Result<KeyBinding, String> {
         }
     }
 
-    let key = match key_part.to_lowercase().as_str() {
+    let mut key = match key_part.to_lowercase().as_str() {
         "enter" => KeyCode::Enter,
         "escape" | "esc" => KeyCode::Escape,
         "backspace" => KeyCode::Backspace,
+        "space" => KeyCode::Char(' '),
         "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
         "up" => KeyCode::Up,
         "down" => KeyCode::Down,
         "left" => KeyCode::Left,
@@ -113,19 +154,50 @@ fn parse_key_binding(s: &str) -> Result<KeyBinding, String> {
         "pagedown" => KeyCode::PageDown,
         "home" => KeyCode::Home,
         "end" => KeyCode::End,
-        s if s.len() == 1 => KeyCode::Char(s.chars().next().unwrap()),
-        s if s.starts_with('f') && s.len() > 1 => {
+        _ if key_part.chars().count() == 1 => KeyCode::Char(key_part.chars().next().unwrap()),
+        s if s.starts_with('f') && s.len() > 1 && s[1..].bytes().all(|b| b.is_ascii_digit()) => {
             let num: u8 = s[1..]
                 .parse()
                 .map_err(|_| format!("Invalid F key: {}", s))?;
             KeyCode::F(num)
         }
-        _ => return Err(format!("Unknown key: {}", key_part)),
+        _ => {
+            return Err(format!(
+                "Unknown key: {} (separate the keys of a sequence with spaces)",
+                key_part
+            ));
+        }
     };
 
+    // Match what the terminal reports: Shift+Tab is BackTab, and shifted letters are uppercase
+    match key {
+        KeyCode::Tab if modifiers.shift => {
+            key = KeyCode::BackTab;
+            modifiers.shift = false;
+        }
+        KeyCode::Char(c) if c.is_ascii_alphabetic() => {
+            if modifiers.shift {
+                key = KeyCode::Char(c.to_ascii_uppercase());
+            }
+            modifiers.shift = c.is_ascii_uppercase() || modifiers.shift;
+        }
+        _ => {}
+    }
+
     Ok(KeyBinding { key, modifiers })
 }
 
+fn parse_key_sequence(s: &str) -> Result<KeySequence, String> {
+    let keys = s
+        .split_whitespace()
+        .map(parse_key_binding)
+        .collect::<Result<Vec<_>, _>>()?;
+    if keys.is_empty() {
+        return Err("Empty key binding".to_string());
+    }
+    Ok(KeySequence(keys))
+}
+
 pub fn key_binding_to_string(binding: &KeyBinding) -> String {
     let mut parts = Vec::new();
 
@@ -140,11 +212,15 @@ pub fn key_binding_to_string(binding: &KeyBinding) -> String {
     }
 
     let key_str = match binding.key {
+        KeyCode::Char(' ') => "Space".to_string(),
         KeyCode::Char(c) => c.to_string(),
         KeyCode::Enter => "Enter".to_string(),
         KeyCode::Escape => "Escape".to_string(),
         KeyCode::Backspace => "Backspace".to_string(),
         KeyCode::Tab => "Tab".to_string(),
+        KeyCode::BackTab => "BackTab".to_string(),
+        KeyCode::Delete => "Delete".to_string(),
+        KeyCode::Insert => "Insert".to_string(),
         KeyCode::Up => "Up".to_string(),
         KeyCode::Down => "Down".to_string(),
         KeyCode::Left => "Left".to_string(),
@@ -160,22 +236,31 @@ pub fn key_binding_to_string(binding: &KeyBinding) -> String {
     parts.join("+")
 }
 
-impl Serialize for KeyBinding {
+pub fn key_sequence_to_string(sequence: &KeySequence) -> String {
+    sequence
+        .keys()
+        .iter()
+        .map(key_binding_to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl Serialize for KeySequence {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&key_binding_to_string(self))
+        serializer.serialize_str(&key_sequence_to_string(self))
     }
 }
 
-impl<'de> Deserialize<'de> for KeyBinding {
+impl<'de> Deserialize<'de> for KeySequence {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        parse_key_binding(&s).map_err(serde::de::Error::custom)
+        parse_key_sequence(&s).map_err(serde::de::Error::custom)
     }
 }
 
@@ -187,6 +272,9 @@ impl From<crossterm::event::KeyEvent> for KeyBinding {
             crossterm::event::KeyCode::Esc => KeyCode::Escape,
             crossterm::event::KeyCode::Backspace => KeyCode::Backspace,
             crossterm::event::KeyCode::Tab => KeyCode::Tab,
+            crossterm::event::KeyCode::BackTab => KeyCode::BackTab,
+            crossterm::event::KeyCode::Delete => KeyCode::Delete,
+            crossterm::event::KeyCode::Insert => KeyCode::Insert,
             crossterm::event::KeyCode::Up => KeyCode::Up,
             crossterm::event::KeyCode::Down => KeyCode::Down,
             crossterm::event::KeyCode::Left => KeyCode::Left,
@@ -210,10 +298,12 @@ impl From<crossterm::event::KeyEvent> for KeyBinding {
             alt: event
                 .modifiers
                 .contains(crossterm::event::KeyModifiers::ALT),
+            // BackTab already implies shift
             shift: is_uppercase_char
-                || event
-                    .modifiers
-                    .contains(crossterm::event::KeyModifiers::SHIFT),
+                || (event.code != crossterm::event::KeyCode::BackTab
+                    && event
+                        .modifiers
+                        .contains(crossterm::event::KeyModifiers::SHIFT)),
         };
 
         Self { key, modifiers }
@@ -223,18 +313,37 @@ impl From<crossterm::event::KeyEvent> for KeyBinding {
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct KeymapConfig {
     #[serde(default)]
-    pub global: HashMap<KeyBinding, GlobalAction>,
+    pub global: HashMap<KeySequence, GlobalAction>,
+    #[serde(default)]
+    pub blame: HashMap<KeySequence, BlameAction>,
+    #[serde(default)]
+    pub diff: HashMap<KeySequence, DiffAction>,
+    #[serde(default)]
+    pub help: HashMap<KeySequence, HelpAction>,
+    #[serde(default)]
+    pub stats: HashMap<KeySequence, StatsAction>,
+    #[serde(default)]
+    pub sessions: HashMap<KeySequence, SessionsAction>,
+    #[serde(default)]
+    pub marks: HashMap<KeySequence, MarksAction>,
+    #[serde(default)]
+    pub issues: HashMap<KeySequence, IssuesAction>,
     #[serde(default)]
-    pub blame: HashMap<KeyBinding, BlameAction>,
+    pub commands: CommandKeymap,
+}
+
+/// Keys bound to user commands, by the name they have under `[commands]`
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct CommandKeymap {
     #[serde(default)]
-    pub diff: HashMap<KeyBinding, DiffAction>,
+    pub blame: HashMap<KeySequence, String>,
     #[serde(default)]
-    pub help: HashMap<KeyBinding, HelpAction>,
+    pub diff: HashMap<KeySequence, String>,
 }
 
 impl KeymapConfig {
     /// Find all keys bound to a specific global action
-    pub fn keys_for_global(&self, action: GlobalAction) -> Vec<&KeyBinding> {
+    pub fn keys_for_global(&self, action: GlobalAction) -> Vec<&KeySequence> {
         self.global
             .iter()
             .filter(|(_, a)| **a == action)
@@ -243,7 +352,7 @@ impl KeymapConfig {
     }
 
     /// Find all keys bound to a specific blame action
-    pub fn keys_for_blame(&self, action: BlameAction) -> Vec<&KeyBinding> {
+    pub fn keys_for_blame(&self, action: BlameAction) -> Vec<&KeySequence> {
         self.blame
             .iter()
             .filter(|(_, a)| **a == action)
@@ -252,7 +361,7 @@ impl KeymapConfig {
     }
 
     /// Find all keys bound to a specific diff action
-    pub fn keys_for_diff(&self, action: DiffAction) -> Vec<&KeyBinding> {
+    pub fn keys_for_diff(&self, action: DiffAction) -> Vec<&KeySequence> {
         self.diff
             .iter()
             .filter(|(_, a)| **a == action)
@@ -262,7 +371,7 @@ impl KeymapConfig {
 
     /// Find all keys bound to a specific help action
     #[allow(dead_code)]
-    pub fn keys_for_help(&self, action: HelpAction) -> Vec<&KeyBinding> {
+    pub fn keys_for_help(&self, action: HelpAction) -> Vec<&KeySequence> {
         self.help
             .iter()
             .filter(|(_, a)| **a == action)
@@ -270,126 +379,497 @@ impl KeymapConfig {
             .collect()
     }
 
+    /// Find all keys bound to a specific stats action
+    pub fn keys_for_stats(&self, action: StatsAction) -> Vec<&KeySequence> {
+        self.stats
+            .iter()
+            .filter(|(_, a)| **a == action)
+            .map(|(k, _)| k)
+            .collect()
+    }
+
+    /// Find all keys bound to a specific sessions action
+    pub fn keys_for_sessions(&self, action: SessionsAction) -> Vec<&KeySequence> {
+        self.sessions
+            .iter()
+            .filter(|(_, a)| **a == action)
+            .map(|(k, _)| k)
+            .collect()
+    }
+
+    /// Find all keys bound to a specific marks action
+    pub fn keys_for_marks(&self, action: MarksAction) -> Vec<&KeySequence> {
+        self.marks
+            .iter()
+            .filter(|(_, a)| **a == action)
+            .map(|(k, _)| k)
+            .collect()
+    }
+
+    /// Find all keys bound to a specific issues action
+    pub fn keys_for_issues(&self, action: IssuesAction) -> Vec<&KeySequence> {
+        self.issues
+            .iter()
+            .filter(|(_, a)| **a == action)
+            .map(|(k, _)| k)
+            .collect()
+    }
+
     pub fn with_defaults() -> Self {
         let mut config = Self::default();
 
         // Global
-        config
-            .global
-            .insert(KeyBinding::new(KeyCode::Char('q')), GlobalAction::Quit);
         config.global.insert(
-            KeyBinding::new(KeyCode::Char('c')).with_modifiers(KeyModifiers::CTRL),
+            KeyBinding::new(KeyCode::Char('q')).into(),
             GlobalAction::Quit,
         );
-        config
-            .global
-            .insert(KeyBinding::new(KeyCode::Char('?')), GlobalAction::ShowHelp);
+        config.global.insert(
+            KeyBinding::new(KeyCode::Char('c'))
+                .with_modifiers(KeyModifiers::CTRL)
+                .into(),
+            GlobalAction::Quit,
+        );
+        config.global.insert(
+            KeyBinding::new(KeyCode::Char('?')).into(),
+            GlobalAction::ShowHelp,
+        );
+        config.global.insert(
+            KeyBinding::new(KeyCode::Char(':')).into(),
+            GlobalAction::CommandLine,
+        );
 
         // Blame
-        config
-            .blame
-            .insert(KeyBinding::new(KeyCode::Char('j')), BlameAction::CursorDown);
         config.blame.insert(
-            KeyBinding::new(KeyCode::Char('J')),
+            KeyBinding::new(KeyCode::Char('j')).into(),
+            BlameAction::CursorDown,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('J')).into(),
             BlameAction::Cursor10Down,
         );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('k')).into(),
+            BlameAction::CursorUp,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('K')).into(),
+            BlameAction::Cursor10Up,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Down).into(),
+            BlameAction::CursorDown,
+        );
         config
             .blame
-            .insert(KeyBinding::new(KeyCode::Char('k')), BlameAction::CursorUp);
-        config
-            .blame
-            .insert(KeyBinding::new(KeyCode::Char('K')), BlameAction::Cursor10Up);
-        config
-            .blame
-            .insert(KeyBinding::new(KeyCode::Down), BlameAction::CursorDown);
-        config
-            .blame
-            .insert(KeyBinding::new(KeyCode::Up), BlameAction::CursorUp);
+            .insert(KeyBinding::new(KeyCode::Up).into(), BlameAction::CursorUp);
         config.blame.insert(
-            KeyBinding::new(KeyCode::Char('d')).with_modifiers(KeyModifiers::CTRL),
+            KeyBinding::new(KeyCode::Char('d'))
+                .with_modifiers(KeyModifiers::CTRL)
+                .into(),
             BlameAction::CursorPageDown,
         );
         config.blame.insert(
-            KeyBinding::new(KeyCode::Char('u')).with_modifiers(KeyModifiers::CTRL),
+            KeyBinding::new(KeyCode::Char('u'))
+                .with_modifiers(KeyModifiers::CTRL)
+                .into(),
             BlameAction::CursorPageUp,
         );
         config
             .blame
-            .insert(KeyBinding::new(KeyCode::Char('g')), BlameAction::CursorTop);
+            .insert(KeySequence::chars("gg"), BlameAction::CursorTop);
         config.blame.insert(
-            KeyBinding::new(KeyCode::Char('G')),
+            KeyBinding::new(KeyCode::Char('G')).into(),
             BlameAction::CursorBottom,
         );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char(',')).into(),
+            BlameAction::DrillDown,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('u')).into(),
+            BlameAction::GoBack,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Enter).into(),
+            BlameAction::ShowDiff,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('s')).into(),
+            BlameAction::ToggleAuthorSubject,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('c')).into(),
+            BlameAction::CycleColoring,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('A')).into(),
+            BlameAction::ToggleAuthorLegend,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('b')).into(),
+            BlameAction::CycleBlockStyle,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('S')).into(),
+            BlameAction::ShowStats,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('R')).into(),
+            BlameAction::ShowSessions,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('m')).into(),
+            BlameAction::SetMark,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('\'')).into(),
+            BlameAction::JumpToMark,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('M')).into(),
+            BlameAction::ShowMarks,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('E')).into(),
+            BlameAction::ExportTrail,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('e')).into(),
+            BlameAction::OpenInEditor,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('i')).into(),
+            BlameAction::ShowIssues,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('I')).into(),
+            BlameAction::HighlightIssue,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('}')).into(),
+            BlameAction::NextHunk,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('{')).into(),
+            BlameAction::PreviousHunk,
+        );
         config
             .blame
-            .insert(KeyBinding::new(KeyCode::Char(',')), BlameAction::DrillDown);
-        config
-            .blame
-            .insert(KeyBinding::new(KeyCode::Char('u')), BlameAction::GoBack);
+            .insert(KeySequence::chars("]c"), BlameAction::NextHunk);
         config
             .blame
-            .insert(KeyBinding::new(KeyCode::Enter), BlameAction::ShowDiff);
+            .insert(KeySequence::chars("[c"), BlameAction::PreviousHunk);
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('y')).into(),
+            BlameAction::YankShortHash,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('Y')).into(),
+            BlameAction::YankHash,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('L')).into(),
+            BlameAction::YankLine,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('T')).into(),
+            BlameAction::YankSubject,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('P')).into(),
+            BlameAction::YankPermalink,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('p')).into(),
+            BlameAction::YankLineLink,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('p'))
+                .with_modifiers(KeyModifiers::CTRL)
+                .into(),
+            BlameAction::YankHunkLink,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('o')).into(),
+            BlameAction::OpenLineLink,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('O')).into(),
+            BlameAction::OpenHunkLink,
+        );
+        config.blame.insert(
+            KeyBinding::new(KeyCode::Char('r')).into(),
+            BlameAction::OpenPullRequest,
+        );
 
         // Diff
-        config
-            .diff
-            .insert(KeyBinding::new(KeyCode::Char('j')), DiffAction::ScrollDown);
         config.diff.insert(
-            KeyBinding::new(KeyCode::Char('J')),
+            KeyBinding::new(KeyCode::Char('j')).into(),
+            DiffAction::ScrollDown,
+        );
+        config.diff.insert(
+            KeyBinding::new(KeyCode::Char('J')).into(),
             DiffAction::Scroll10Down,
         );
+        config.diff.insert(
+            KeyBinding::new(KeyCode::Char('k')).into(),
+            DiffAction::ScrollUp,
+        );
+        config.diff.insert(
+            KeyBinding::new(KeyCode::Char('K')).into(),
+            DiffAction::Scroll10Up,
+        );
+        config.diff.insert(
+            KeyBinding::new(KeyCode::Down).into(),
+            DiffAction::ScrollDown,
+        );
         config
             .diff
-            .insert(KeyBinding::new(KeyCode::Char('k')), DiffAction::ScrollUp);
-        config
-            .diff
-            .insert(KeyBinding::new(KeyCode::Char('K')), DiffAction::Scroll10Up);
-        config
-            .diff
-            .insert(KeyBinding::new(KeyCode::Down), DiffAction::ScrollDown);
+            .insert(KeyBinding::new(KeyCode::Up).into(), DiffAction::ScrollUp);
+        config.diff.insert(
+            KeyBinding::new(KeyCode::Char('d'))
+                .with_modifiers(KeyModifiers::CTRL)
+                .into(),
+            DiffAction::ScrollPageDown,
+        );
         config
             .diff
-            .insert(KeyBinding::new(KeyCode::Up), DiffAction::ScrollUp);
+            .insert(KeySequence::chars("gg"), DiffAction::ScrollTop);
         config.diff.insert(
-            KeyBinding::new(KeyCode::Char('d')).with_modifiers(KeyModifiers::CTRL),
-            DiffAction::ScrollPageDown,
+            KeyBinding::new(KeyCode::Char('G')).into(),
+            DiffAction::ScrollBottom,
         );
         config.diff.insert(
-            KeyBinding::new(KeyCode::Char('u')).with_modifiers(KeyModifiers::CTRL),
+            KeyBinding::new(KeyCode::Char('u'))
+                .with_modifiers(KeyModifiers::CTRL)
+                .into(),
             DiffAction::ScrollPageUp,
         );
+        config.diff.insert(
+            KeyBinding::new(KeyCode::Char('q')).into(),
+            DiffAction::Close,
+        );
         config
             .diff
-            .insert(KeyBinding::new(KeyCode::Char('q')), DiffAction::Close);
-        config
-            .diff
-            .insert(KeyBinding::new(KeyCode::Escape), DiffAction::Close);
+            .insert(KeyBinding::new(KeyCode::Escape).into(), DiffAction::Close);
+        config.diff.insert(
+            KeyBinding::new(KeyCode::Char('o')).into(),
+            DiffAction::OpenInBrowser,
+        );
+        config.diff.insert(
+            KeyBinding::new(KeyCode::Char('r')).into(),
+            DiffAction::OpenPullRequest,
+        );
+        config.diff.insert(
+            KeyBinding::new(KeyCode::Char('i')).into(),
+            DiffAction::ShowIssues,
+        );
+        config.diff.insert(
+            KeyBinding::new(KeyCode::Char('y')).into(),
+            DiffAction::YankShortHash,
+        );
+        config.diff.insert(
+            KeyBinding::new(KeyCode::Char('Y')).into(),
+            DiffAction::YankHash,
+        );
+        config.diff.insert(
+            KeyBinding::new(KeyCode::Char('T')).into(),
+            DiffAction::YankSubject,
+        );
         config.diff.insert(
-            KeyBinding::new(KeyCode::Char('o')),
-            DiffAction::OpenInGitHub,
+            KeyBinding::new(KeyCode::Char('P')).into(),
+            DiffAction::YankPermalink,
         );
 
         // Help
-        config
-            .help
-            .insert(KeyBinding::new(KeyCode::Char('j')), HelpAction::ScrollDown);
-        config
-            .help
-            .insert(KeyBinding::new(KeyCode::Char('k')), HelpAction::ScrollUp);
         config.help.insert(
-            KeyBinding::new(KeyCode::Char('J')),
+            KeyBinding::new(KeyCode::Char('j')).into(),
+            HelpAction::ScrollDown,
+        );
+        config.help.insert(
+            KeyBinding::new(KeyCode::Char('k')).into(),
+            HelpAction::ScrollUp,
+        );
+        config.help.insert(
+            KeyBinding::new(KeyCode::Char('J')).into(),
             HelpAction::Scroll10Down,
         );
+        config.help.insert(
+            KeyBinding::new(KeyCode::Char('K')).into(),
+            HelpAction::Scroll10Up,
+        );
+        config.help.insert(
+            KeyBinding::new(KeyCode::Char('q')).into(),
+            HelpAction::Close,
+        );
         config
             .help
-            .insert(KeyBinding::new(KeyCode::Char('K')), HelpAction::Scroll10Up);
+            .insert(KeyBinding::new(KeyCode::Escape).into(), HelpAction::Close);
+
+        // Stats
+        config.stats.insert(
+            KeyBinding::new(KeyCode::Char('j')).into(),
+            StatsAction::CursorDown,
+        );
+        config.stats.insert(
+            KeyBinding::new(KeyCode::Char('k')).into(),
+            StatsAction::CursorUp,
+        );
+        config.stats.insert(
+            KeyBinding::new(KeyCode::Down).into(),
+            StatsAction::CursorDown,
+        );
         config
-            .help
-            .insert(KeyBinding::new(KeyCode::Char('q')), HelpAction::Close);
+            .stats
+            .insert(KeyBinding::new(KeyCode::Up).into(), StatsAction::CursorUp);
         config
-            .help
-            .insert(KeyBinding::new(KeyCode::Escape), HelpAction::Close);
+            .stats
+            .insert(KeyBinding::new(KeyCode::Enter).into(), StatsAction::Select);
+        config.stats.insert(
+            KeyBinding::new(KeyCode::Char('q')).into(),
+            StatsAction::Close,
+        );
+        config
+            .stats
+            .insert(KeyBinding::new(KeyCode::Escape).into(), StatsAction::Close);
+
+        // Sessions
+        config.sessions.insert(
+            KeyBinding::new(KeyCode::Char('j')).into(),
+            SessionsAction::CursorDown,
+        );
+        config.sessions.insert(
+            KeyBinding::new(KeyCode::Char('k')).into(),
+            SessionsAction::CursorUp,
+        );
+        config.sessions.insert(
+            KeyBinding::new(KeyCode::Down).into(),
+            SessionsAction::CursorDown,
+        );
+        config.sessions.insert(
+            KeyBinding::new(KeyCode::Up).into(),
+            SessionsAction::CursorUp,
+        );
+        config.sessions.insert(
+            KeyBinding::new(KeyCode::Enter).into(),
+            SessionsAction::Select,
+        );
+        config.sessions.insert(
+            KeyBinding::new(KeyCode::Char('d')).into(),
+            SessionsAction::Delete,
+        );
+        config.sessions.insert(
+            KeyBinding::new(KeyCode::Char('q')).into(),
+            SessionsAction::Close,
+        );
+        config.sessions.insert(
+            KeyBinding::new(KeyCode::Escape).into(),
+            SessionsAction::Close,
+        );
+
+        // Marks
+        config.marks.insert(
+            KeyBinding::new(KeyCode::Char('j')).into(),
+            MarksAction::CursorDown,
+        );
+        config.marks.insert(
+            KeyBinding::new(KeyCode::Char('k')).into(),
+            MarksAction::CursorUp,
+        );
+        config.marks.insert(
+            KeyBinding::new(KeyCode::Down).into(),
+            MarksAction::CursorDown,
+        );
+        config
+            .marks
+            .insert(KeyBinding::new(KeyCode::Up).into(), MarksAction::CursorUp);
+        config
+            .marks
+            .insert(KeyBinding::new(KeyCode::Enter).into(), MarksAction::Select);
+        config.marks.insert(
+            KeyBinding::new(KeyCode::Char('d')).into(),
+            MarksAction::Delete,
+        );
+        config.marks.insert(
+            KeyBinding::new(KeyCode::Char('q')).into(),
+            MarksAction::Close,
+        );
+        config
+            .marks
+            .insert(KeyBinding::new(KeyCode::Escape).into(), MarksAction::Close);
+
+        // Issues
+        config.issues.insert(
+            KeyBinding::new(KeyCode::Char('j')).into(),
+            IssuesAction::CursorDown,
+        );
+        config.issues.insert(
+            KeyBinding::new(KeyCode::Char('k')).into(),
+            IssuesAction::CursorUp,
+        );
+        config.issues.insert(
+            KeyBinding::new(KeyCode::Down).into(),
+            IssuesAction::CursorDown,
+        );
+        config
+            .issues
+            .insert(KeyBinding::new(KeyCode::Up).into(), IssuesAction::CursorUp);
+        config
+            .issues
+            .insert(KeyBinding::new(KeyCode::Enter).into(), IssuesAction::Select);
+        config.issues.insert(
+            KeyBinding::new(KeyCode::Char('h')).into(),
+            IssuesAction::Highlight,
+        );
+        config.issues.insert(
+            KeyBinding::new(KeyCode::Char('q')).into(),
+            IssuesAction::Close,
+        );
+        config
+            .issues
+            .insert(KeyBinding::new(KeyCode::Escape).into(), IssuesAction::Close);
 
         config
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sequences_and_key_names() {
+        assert_eq!(parse_key_sequence("g g"), Ok(KeySequence::chars("gg")));
+        assert_eq!(
+            parse_key_sequence("] Ctrl+c"),
+            Ok(KeySequence(vec![
+                KeyBinding::new(KeyCode::Char(']')),
+                KeyBinding::new(KeyCode::Char('c')).with_modifiers(KeyModifiers::CTRL),
+            ]))
+        );
+        assert!(parse_key_sequence("gg").is_err());
+        assert!(parse_key_sequence(" ").is_err());
+
+        // Letters keep their case, and Shift+letter is the uppercase letter
+        assert_eq!(
+            parse_key_binding("G"),
+            Ok(KeyBinding::new(KeyCode::Char('G')))
+        );
+        assert_eq!(
+            parse_key_binding("Shift+g"),
+            Ok(KeyBinding::new(KeyCode::Char('G')))
+        );
+        assert_eq!(
+            parse_key_binding("Shift+Tab"),
+            Ok(KeyBinding::new(KeyCode::BackTab))
+        );
+        assert_eq!(
+            parse_key_binding("Space"),
+            Ok(KeyBinding::new(KeyCode::Char(' ')))
+        );
+        assert_eq!(
+            key_sequence_to_string(&parse_key_sequence("Space Del").unwrap()),
+            "Space Delete"
+        );
+    }
+}
@@ -3,7 +3,7 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 
-use super::{AppConfig, KeymapConfig};
+use super::{AppConfig, KeymapConfig, key_sequence_to_string};
 
 pub struct ConfigLoader;
 
@@ -29,6 +29,29 @@ impl ConfigLoader {
             for (k, v) in defaults.help {
                 config.keymap.help.entry(k).or_insert(v);
             }
+            for (k, v) in defaults.stats {
+                config.keymap.stats.entry(k).or_insert(v);
+            }
+            for (k, v) in defaults.sessions {
+                config.keymap.sessions.entry(k).or_insert(v);
+            }
+            for (k, v) in defaults.marks {
+                config.keymap.marks.entry(k).or_insert(v);
+            }
+            for (k, v) in defaults.issues {
+                config.keymap.issues.entry(k).or_insert(v);
+            }
+
+            let bindings = &config.keymap.commands;
+            for (key, name) in bindings.blame.iter().chain(&bindings.diff) {
+                if !config.commands.contains_key(name) {
+                    anyhow::bail!(
+                        "{} is bound to `{}`, which is not defined under [commands]",
+                        key_sequence_to_string(key),
+                        name
+                    );
+                }
+            }
 
             Ok(config)
         } else {
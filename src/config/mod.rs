@@ -1,8 +1,32 @@
+mod blame;
+mod clipboard;
+mod commands;
+mod diff;
+mod editor;
+mod export;
+mod forge;
+mod issues;
 mod keymap;
 mod loader;
+pub(crate) mod theme;
 
-pub use keymap::{KeyBinding, KeymapConfig, key_binding_to_string};
+pub use blame::{
+    BlameConfig, BlockStyle, Coloring, ColumnKind, HeatmapConfig, HeatmapScale, HeatmapTarget,
+};
+pub use clipboard::{ClipboardConfig, ClipboardMethod};
+pub use commands::{CommandOutput, UserCommand};
+pub use diff::{DiffConfig, HeaderField};
+pub use editor::EditorConfig;
+pub use export::{ExportConfig, ExportFormat};
+pub use forge::{ForgeConfig, ForgeKind};
+pub use issues::IssueTracker;
+pub use keymap::{
+    KeyBinding, KeyCode, KeySequence, KeymapConfig, key_binding_to_string, key_sequence_to_string,
+};
 pub use loader::ConfigLoader;
+pub use theme::{StyleConfig, ThemeConfig, ThemeName, ThemeStyles};
+
+use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
@@ -11,6 +35,25 @@ pub struct AppConfig {
     #[serde(default)]
     pub general: GeneralConfig,
     #[serde(default)]
+    pub blame: BlameConfig,
+    #[serde(default)]
+    pub diff: DiffConfig,
+    #[serde(default)]
+    pub export: ExportConfig,
+    #[serde(default)]
+    pub clipboard: ClipboardConfig,
+    #[serde(default)]
+    pub forge: ForgeConfig,
+    #[serde(default)]
+    pub issues: Vec<IssueTracker>,
+    #[serde(default)]
+    pub editor: EditorConfig,
+    /// Named shell commands, bound to keys under `[keymap.commands]`
+    #[serde(default)]
+    pub commands: HashMap<String, UserCommand>,
+    #[serde(default)]
+    pub theme: ThemeConfig,
+    #[serde(default)]
     pub keymap: KeymapConfig,
 }
 
@@ -18,6 +61,15 @@ impl Default for AppConfig {
     fn default() -> Self {
         Self {
             general: GeneralConfig::default(),
+            blame: BlameConfig::default(),
+            diff: DiffConfig::default(),
+            export: ExportConfig::default(),
+            clipboard: ClipboardConfig::default(),
+            forge: ForgeConfig::default(),
+            issues: Vec::new(),
+            editor: EditorConfig::default(),
+            commands: HashMap::new(),
+            theme: ThemeConfig::default(),
             keymap: KeymapConfig::with_defaults(),
         }
     }
@@ -27,16 +79,40 @@ impl Default for AppConfig {
 pub struct GeneralConfig {
     #[serde(default = "default_formatter")]
     pub diff_formatter: String,
+    /// Resolve author names through the repository's mailmap
+    #[serde(default = "default_true")]
+    pub mailmap: bool,
+    /// Show the raw commit identity next to mailmap-resolved names
+    #[serde(default)]
+    pub show_raw_identity: bool,
+    /// Save the exploration on quit so it can be resumed
+    #[serde(default = "default_true")]
+    pub save_sessions: bool,
+    /// Milliseconds to wait for the next key of a multi-key binding such as `g g`
+    #[serde(default = "default_key_timeout")]
+    pub key_timeout_ms: u64,
 }
 
 fn default_formatter() -> String {
     "delta".to_string()
 }
 
+fn default_true() -> bool {
+    true
+}
+
+fn default_key_timeout() -> u64 {
+    1000
+}
+
 impl Default for GeneralConfig {
     fn default() -> Self {
         Self {
             diff_formatter: default_formatter(),
+            mailmap: true,
+            show_raw_identity: false,
+            save_sessions: true,
+            key_timeout_ms: default_key_timeout(),
         }
     }
 }
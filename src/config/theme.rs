@@ -0,0 +1,143 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Built-in theme to start from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum ThemeName {
+    /// Pick `Dark` or `Light` from the terminal background
+    #[default]
+    Auto,
+    Dark,
+    Light,
+    HighContrast,
+}
+
+/// A colour value: a name (`"blue"`), a 256-colour index (`"208"`) or truecolor (`"#ff8800"`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorValue(pub Color);
+
+impl Serialize for ColorValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse()
+            .map(ColorValue)
+            .map_err(|_| serde::de::Error::custom(format!("Invalid color: {}", s)))
+    }
+}
+
+/// Style override for one theme slot
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StyleConfig {
+    #[serde(default)]
+    pub fg: Option<ColorValue>,
+    #[serde(default)]
+    pub bg: Option<ColorValue>,
+    #[serde(default)]
+    pub bold: bool,
+    #[serde(default)]
+    pub dim: bool,
+    #[serde(default)]
+    pub italic: bool,
+    #[serde(default)]
+    pub underlined: bool,
+    #[serde(default)]
+    pub reversed: bool,
+}
+
+/// Per-slot overrides on top of the base theme
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ThemeStyles {
+    // Blame view
+    pub hash_newest: Option<StyleConfig>,
+    pub hash_new: Option<StyleConfig>,
+    pub hash_old: Option<StyleConfig>,
+    pub hash_oldest: Option<StyleConfig>,
+    pub author: Option<StyleConfig>,
+    pub author_email: Option<StyleConfig>,
+    pub date: Option<StyleConfig>,
+    pub age: Option<StyleConfig>,
+    pub line_number: Option<StyleConfig>,
+    pub original_path: Option<StyleConfig>,
+    pub subject: Option<StyleConfig>,
+    pub trail_index: Option<StyleConfig>,
+    pub content: Option<StyleConfig>,
+    pub hunk_alternate: Option<StyleConfig>,
+    pub owner_highlight: Option<StyleConfig>,
+
+    // Shared
+    pub selection: Option<StyleConfig>,
+    pub border: Option<StyleConfig>,
+    pub title: Option<StyleConfig>,
+
+    // Status bar
+    pub status_bar: Option<StyleConfig>,
+    pub status_mode: Option<StyleConfig>,
+    pub status_chain: Option<StyleConfig>,
+    pub status_message: Option<StyleConfig>,
+
+    // Diff view
+    pub diff_hash: Option<StyleConfig>,
+    pub diff_refs: Option<StyleConfig>,
+    pub diff_issue: Option<StyleConfig>,
+    pub diff_label: Option<StyleConfig>,
+
+    // Help view
+    pub help_title: Option<StyleConfig>,
+    pub help_section: Option<StyleConfig>,
+}
+
+impl ThemeStyles {
+    /// Every slot, in the same order as `Theme::slots_mut`
+    pub fn slots(&self) -> [&Option<StyleConfig>; 28] {
+        [
+            &self.hash_newest,
+            &self.hash_new,
+            &self.hash_old,
+            &self.hash_oldest,
+            &self.author,
+            &self.author_email,
+            &self.date,
+            &self.age,
+            &self.line_number,
+            &self.original_path,
+            &self.subject,
+            &self.trail_index,
+            &self.content,
+            &self.hunk_alternate,
+            &self.owner_highlight,
+            &self.selection,
+            &self.border,
+            &self.title,
+            &self.status_bar,
+            &self.status_mode,
+            &self.status_chain,
+            &self.status_message,
+            &self.diff_hash,
+            &self.diff_refs,
+            &self.diff_issue,
+            &self.diff_label,
+            &self.help_title,
+            &self.help_section,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ThemeConfig {
+    #[serde(default)]
+    pub base: ThemeName,
+    #[serde(default)]
+    pub styles: ThemeStyles,
+}
@@ -1,14 +1,71 @@
+use std::collections::HashMap;
+use std::ops::Range;
 use std::path::PathBuf;
 
 use crate::domain::CommitHash;
+use crate::domain::commit::identity_label;
 
 #[derive(Debug, Clone)]
 pub struct BlameEntry {
     pub line_number: usize,
     pub commit_hash: CommitHash,
     pub author: String,
+    /// Name as recorded in the commit, when the mailmap resolved it to a different one
+    pub raw_author: Option<String>,
+    pub author_email: String,
     pub timestamp: i64,
+    /// Author's offset from UTC in minutes
+    pub offset_minutes: i32,
+    /// Subject line of the commit that last changed this line
+    pub summary: String,
     pub content: String,
+    /// Line number in the commit that introduced the line
+    pub original_line_number: usize,
+    /// Path in the commit that introduced the line, when it differs from the frame's path
+    pub original_path: Option<PathBuf>,
+    /// Line is older than the history boundary and attributed to the boundary commit
+    pub boundary: bool,
+}
+
+impl BlameEntry {
+    /// Author name for display, optionally followed by the raw identity
+    pub fn author_label(&self, show_raw: bool) -> String {
+        identity_label(&self.author, self.raw_author.as_deref(), show_raw)
+    }
+
+    /// Stable identity of the author: the lowercased email, or the name when there is none
+    pub fn author_key(&self) -> String {
+        if self.author_email.is_empty() {
+            self.author.clone()
+        } else {
+            self.author_email.to_lowercase()
+        }
+    }
+}
+
+/// Lines attributed to one author in a frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorLines {
+    /// See `BlameEntry::author_key`
+    pub key: String,
+    pub author: String,
+    pub raw_author: Option<String>,
+    pub lines: usize,
+}
+
+impl AuthorLines {
+    pub fn author_label(&self, show_raw: bool) -> String {
+        identity_label(&self.author, self.raw_author.as_deref(), show_raw)
+    }
+}
+
+/// Lower bound on how far back blame attribution goes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HistoryBoundary {
+    /// Stop at this commit (the left side of `rev..rev`)
+    Commit(CommitHash),
+    /// Stop at the first commit older than this unix timestamp (`--since`)
+    Since(i64),
 }
 
 #[derive(Debug, Clone)]
@@ -16,7 +73,69 @@ pub struct BlameFrame {
     pub file_path: PathBuf,
     pub commit_hash: CommitHash,
     pub entries: Vec<BlameEntry>,
+    /// Blame hunks as ranges of `entries`, in order and covering every entry
+    pub hunks: Vec<Range<usize>>,
     pub selected_line: usize,
+    /// First visible row, kept so returning to a frame restores its view
+    pub scroll_offset: usize,
+    /// Commit whose parent this frame was blamed at, when it was reached by drilling down
+    pub drilled_through: Option<CommitHash>,
+}
+
+impl BlameFrame {
+    /// Index of the hunk containing the given entry
+    pub fn hunk_index(&self, line: usize) -> Option<usize> {
+        let index = self.hunks.partition_point(|h| h.end <= line);
+        self.hunks.get(index).filter(|h| h.contains(&line))?;
+        Some(index)
+    }
+
+    /// Whether the entry is the first line of its hunk
+    pub fn is_hunk_start(&self, line: usize) -> bool {
+        self.hunk_index(line)
+            .is_none_or(|index| self.hunks[index].start == line)
+    }
+
+    /// First line of the hunk after the one containing `line`
+    pub fn next_hunk_start(&self, line: usize) -> Option<usize> {
+        let index = self.hunk_index(line)?;
+        self.hunks.get(index + 1).map(|h| h.start)
+    }
+
+    /// First line of the current hunk, or of the previous one when already there
+    pub fn previous_hunk_start(&self, line: usize) -> Option<usize> {
+        let index = self.hunk_index(line)?;
+        let hunk = &self.hunks[index];
+        if line > hunk.start {
+            Some(hunk.start)
+        } else {
+            index.checked_sub(1).map(|i| self.hunks[i].start)
+        }
+    }
+
+    /// Line counts per author, most lines first
+    pub fn authors(&self) -> Vec<AuthorLines> {
+        let mut authors: Vec<AuthorLines> = Vec::new();
+        // Position of each author key in `authors`
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for entry in &self.entries {
+            let key = entry.author_key();
+            match index.get(&key) {
+                Some(&i) => authors[i].lines += 1,
+                None => {
+                    index.insert(key.clone(), authors.len());
+                    authors.push(AuthorLines {
+                        key,
+                        author: entry.author.clone(),
+                        raw_author: entry.raw_author.clone(),
+                        lines: 1,
+                    });
+                }
+            }
+        }
+        authors.sort_by(|a, b| b.lines.cmp(&a.lines).then_with(|| a.author.cmp(&b.author)));
+        authors
+    }
 }
 
 #[derive(Debug, Default)]
@@ -49,6 +168,19 @@ impl BlameStack {
         self.frames.len()
     }
 
+    /// Frames from the starting revision to the current one
+    pub fn frames(&self) -> &[BlameFrame] {
+        &self.frames
+    }
+
+    /// 1-based position of the frame that was drilled through `commit`, if any was
+    pub fn trail_position(&self, commit: &CommitHash) -> Option<usize> {
+        // Frame i was reached from frame i - 1, which is number i counting from 1
+        self.frames
+            .iter()
+            .position(|f| f.drilled_through.as_ref() == Some(commit))
+    }
+
     #[allow(dead_code)]
     pub fn is_empty(&self) -> bool {
         self.frames.is_empty()
@@ -70,6 +202,98 @@ impl BlameStack {
     }
 }
 
+/// Fixtures for tests elsewhere in the crate
+#[cfg(test)]
+impl BlameEntry {
+    /// A line of `commit` by `author`, with an `<author>@example.com` email and timestamp 0
+    pub fn fixture(commit: &str, author: &str) -> Self {
+        Self {
+            line_number: 1,
+            commit_hash: CommitHash::new(commit),
+            author: author.to_string(),
+            raw_author: None,
+            author_email: format!("{}@example.com", author.to_lowercase()),
+            timestamp: 0,
+            offset_minutes: 0,
+            summary: String::new(),
+            content: String::new(),
+            original_line_number: 1,
+            original_path: None,
+            boundary: false,
+        }
+    }
+
+    pub fn with_email(mut self, email: &str) -> Self {
+        self.author_email = email.to_string();
+        self
+    }
+
+    pub fn with_time(mut self, timestamp: i64, offset_minutes: i32) -> Self {
+        self.timestamp = timestamp;
+        self.offset_minutes = offset_minutes;
+        self
+    }
+
+    pub fn with_summary(mut self, summary: &str) -> Self {
+        self.summary = summary.to_string();
+        self
+    }
+
+    pub fn with_content(mut self, content: &str) -> Self {
+        self.content = content.to_string();
+        self
+    }
+}
+
+#[cfg(test)]
+impl BlameFrame {
+    /// A frame of `a.rs` at `revision`, numbering the entries from 1 and grouping
+    /// consecutive lines of one commit into a hunk
+    pub fn fixture(revision: &str, mut entries: Vec<BlameEntry>) -> Self {
+        let mut hunks: Vec<Range<usize>> = Vec::new();
+        for (i, entry) in entries.iter_mut().enumerate() {
+            entry.line_number = i + 1;
+            entry.original_line_number = i + 1;
+        }
+        for (i, entry) in entries.iter().enumerate() {
+            match hunks.last_mut() {
+                Some(h) if entries[h.start].commit_hash == entry.commit_hash => h.end = i + 1,
+                _ => hunks.push(i..i + 1),
+            }
+        }
+        Self {
+            file_path: "a.rs".into(),
+            commit_hash: CommitHash::new(revision),
+            entries,
+            hunks,
+            selected_line: 0,
+            scroll_offset: 0,
+            drilled_through: None,
+        }
+    }
+
+    pub fn with_path(mut self, path: &str) -> Self {
+        self.file_path = path.into();
+        self
+    }
+
+    pub fn with_hunks(mut self, hunks: Vec<Range<usize>>) -> Self {
+        self.hunks = hunks;
+        self
+    }
+
+    pub fn with_position(mut self, selected_line: usize, scroll_offset: usize) -> Self {
+        self.selected_line = selected_line;
+        self.scroll_offset = scroll_offset;
+        self
+    }
+
+    pub fn with_drilled_through(mut self, commit: &str) -> Self {
+        self.drilled_through = Some(CommitHash::new(commit));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,11 +302,10 @@ mod tests {
 
     // テスト用のBlameFrameを生成する戦略
     fn arbitrary_frame() -> impl Strategy<Value = BlameFrame> {
-        ("[a-z]{1,20}", "[0-9a-f]{40}", 0..1000usize).prop_map(|(path, hash, line)| BlameFrame {
-            file_path: path.into(),
-            commit_hash: CommitHash::new(hash),
-            entries: vec![],
-            selected_line: line,
+        ("[a-z]{1,20}", "[0-9a-f]{40}", 0..1000usize).prop_map(|(path, hash, line)| {
+            BlameFrame::fixture(&hash, vec![])
+                .with_path(&path)
+                .with_position(line, 0)
         })
     }
 
@@ -140,6 +363,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn authors_are_grouped_by_email_and_sorted_by_lines() {
+        let frame = BlameFrame::fixture(
+            "head",
+            vec![
+                BlameEntry::fixture("abc1234", "Bob"),
+                BlameEntry::fixture("abc1234", "Alice"),
+                BlameEntry::fixture("abc1234", "Alice").with_email("Alice@Example.com"),
+                BlameEntry::fixture("abc1234", "Carol").with_email(""),
+            ],
+        );
+        let authors: Vec<(String, usize)> = frame
+            .authors()
+            .into_iter()
+            .map(|a| (a.author, a.lines))
+            .collect();
+        assert_eq!(
+            authors,
+            vec![
+                ("Alice".to_string(), 2),
+                ("Bob".to_string(), 1),
+                ("Carol".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn hunk_motions_move_between_hunk_starts() {
+        let entries = vec![BlameEntry::fixture("abc1234", "Alice"); 8];
+        let frame = BlameFrame::fixture("head", entries).with_hunks(vec![0..3, 3..4, 4..8]);
+        assert_eq!(frame.hunk_index(5), Some(2));
+        assert!(frame.is_hunk_start(3));
+        assert!(!frame.is_hunk_start(5));
+
+        assert_eq!(frame.next_hunk_start(1), Some(3));
+        assert_eq!(frame.next_hunk_start(3), Some(4));
+        assert_eq!(frame.next_hunk_start(6), None);
+
+        assert_eq!(frame.previous_hunk_start(6), Some(4));
+        assert_eq!(frame.previous_hunk_start(4), Some(3));
+        assert_eq!(frame.previous_hunk_start(0), None);
+    }
+
     #[test]
     fn empty_stack_pop_returns_none() {
         let mut stack = BlameStack::new();
@@ -159,6 +425,18 @@ mod tests {
         assert_eq!(stack.depth(), 0);
     }
 
+    #[test]
+    fn trail_position_numbers_the_frame_drilled_from() {
+        let mut stack = BlameStack::new();
+        stack.push(BlameFrame::fixture("aaaa", vec![]));
+        stack.push(BlameFrame::fixture("bbbb", vec![]).with_drilled_through("1111"));
+        stack.push(BlameFrame::fixture("cccc", vec![]).with_drilled_through("2222"));
+
+        assert_eq!(stack.trail_position(&CommitHash::new("1111")), Some(1));
+        assert_eq!(stack.trail_position(&CommitHash::new("2222")), Some(2));
+        assert_eq!(stack.trail_position(&CommitHash::new("bbbb")), None);
+    }
+
     #[test]
     fn hash_chain_empty_stack_returns_none() {
         let stack = BlameStack::new();
@@ -168,30 +446,24 @@ mod tests {
     #[test]
     fn hash_chain_single_frame_returns_none() {
         let mut stack = BlameStack::new();
-        stack.push(BlameFrame {
-            file_path: "test.rs".into(),
-            commit_hash: CommitHash::new("abc123def456789012345678901234567890abcd".to_string()),
-            entries: vec![],
-            selected_line: 0,
-        });
+        stack.push(
+            BlameFrame::fixture("abc123def456789012345678901234567890abcd", vec![])
+                .with_path("test.rs"),
+        );
         assert!(stack.hash_chain().is_none());
     }
 
     #[test]
     fn hash_chain_two_frames_returns_chain() {
         let mut stack = BlameStack::new();
-        stack.push(BlameFrame {
-            file_path: "test.rs".into(),
-            commit_hash: CommitHash::new("1111111111111111111111111111111111111111".to_string()),
-            entries: vec![],
-            selected_line: 0,
-        });
-        stack.push(BlameFrame {
-            file_path: "test.rs".into(),
-            commit_hash: CommitHash::new("2222222222222222222222222222222222222222".to_string()),
-            entries: vec![],
-            selected_line: 0,
-        });
+        stack.push(
+            BlameFrame::fixture("1111111111111111111111111111111111111111", vec![])
+                .with_path("test.rs"),
+        );
+        stack.push(
+            BlameFrame::fixture("2222222222222222222222222222222222222222", vec![])
+                .with_path("test.rs"),
+        );
 
         let chain = stack.hash_chain().unwrap();
         assert_eq!(chain, "1111111 -> 2222222");
@@ -208,12 +480,7 @@ mod tests {
         ];
 
         for hash in hashes {
-            stack.push(BlameFrame {
-                file_path: "test.rs".into(),
-                commit_hash: CommitHash::new(hash.to_string()),
-                entries: vec![],
-                selected_line: 0,
-            });
+            stack.push(BlameFrame::fixture(hash, vec![]).with_path("test.rs"));
         }
 
         let chain = stack.hash_chain().unwrap();
@@ -3,10 +3,48 @@ use std::fmt;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CommitInfo {
     pub hash: CommitHash,
-    pub parent: Option<CommitHash>,
-    pub author: String,
-    pub timestamp: i64,
+    /// All parents in order; merge commits have more than one
+    pub parents: Vec<CommitHash>,
+    pub author: Signature,
+    pub committer: Signature,
     pub message: String,
+    /// Branch and tag names pointing at this commit
+    pub refs: Vec<String>,
+    /// `Key: value` trailers from the end of the message
+    pub trailers: Vec<(String, String)>,
+}
+
+/// Who made a commit and when
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub name: String,
+    pub email: String,
+    /// Name as recorded in the commit, when the mailmap resolved it to a different one
+    pub raw_name: Option<String>,
+    pub timestamp: i64,
+    /// Offset from UTC in minutes, as recorded in the commit
+    pub offset_minutes: i32,
+}
+
+impl CommitInfo {
+    /// First parent, which is the one blame history follows
+    pub fn parent(&self) -> Option<&CommitHash> {
+        self.parents.first()
+    }
+}
+
+impl Signature {
+    /// Name for display, optionally followed by the raw identity
+    pub fn name_label(&self, show_raw: bool) -> String {
+        identity_label(&self.name, self.raw_name.as_deref(), show_raw)
+    }
+}
+
+pub(crate) fn identity_label(author: &str, raw_author: Option<&str>, show_raw: bool) -> String {
+    match raw_author {
+        Some(raw) if show_raw => format!("{} ({})", author, raw),
+        _ => author.to_string(),
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -52,6 +90,37 @@ mod tests {
     use super::*;
     use proptest::prelude::*;
 
+    fn signature(name: &str) -> Signature {
+        Signature {
+            name: name.to_string(),
+            email: format!("{}@example.com", name),
+            raw_name: None,
+            timestamp: 0,
+            offset_minutes: 0,
+        }
+    }
+
+    #[test]
+    fn parent_is_first_of_merge_parents() {
+        let info = CommitInfo {
+            hash: CommitHash::new("c"),
+            parents: vec![CommitHash::new("a"), CommitHash::new("b")],
+            author: signature("alice"),
+            committer: signature("bob"),
+            message: String::new(),
+            refs: vec![],
+            trailers: vec![],
+        };
+        assert_eq!(info.parent(), Some(&CommitHash::new("a")));
+    }
+
+    #[test]
+    fn identity_label_shows_raw_only_when_requested_and_different() {
+        assert_eq!(identity_label("Jane", Some("jdoe"), true), "Jane (jdoe)");
+        assert_eq!(identity_label("Jane", Some("jdoe"), false), "Jane");
+        assert_eq!(identity_label("Jane", None, true), "Jane");
+    }
+
     proptest! {
         #[test]
         fn short_is_at_most_7_chars(s in "[0-9a-f]{40}") {
@@ -0,0 +1,292 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, FixedOffset, Local};
+use serde::{Deserialize, Serialize};
+
+/// Current time as a unix timestamp
+pub fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+/// Describe how long ago `timestamp` was relative to `now`, e.g. "3 months ago"
+pub fn relative_age(timestamp: i64, now: i64) -> String {
+    let secs = now - timestamp;
+    if secs < 0 {
+        return "in the future".to_string();
+    }
+
+    let (amount, unit) = match secs {
+        s if s < 60 => return "just now".to_string(),
+        s if s < 3600 => (s / 60, "minute"),
+        s if s < 86400 => (s / 3600, "hour"),
+        s if s < 14 * 86400 => (s / 86400, "day"),
+        s if s < 60 * 86400 => (s / (7 * 86400), "week"),
+        s if s < 365 * 86400 => (s / (30 * 86400), "month"),
+        s => (s / (365 * 86400), "year"),
+    };
+
+    let plural = if amount == 1 { "" } else { "s" };
+    format!("{} {}{} ago", amount, unit, plural)
+}
+
+/// Date layouts, named after git's `--date` formats
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateStyle {
+    Relative,
+    Iso,
+    IsoStrict,
+    Rfc,
+    Short,
+    Default,
+    Unix,
+    Raw,
+    /// strftime pattern, as in `format:<pattern>`
+    Custom(String),
+}
+
+/// How to display a timestamp, parsed from git's `--date` / `blame.date` syntax.
+///
+/// Dates are shown in the signature's own timezone unless `local` is set,
+/// which converts them to the local timezone (the `-local` suffix in git).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateFormat {
+    pub style: DateStyle,
+    pub local: bool,
+}
+
+impl DateFormat {
+    pub fn new(style: DateStyle) -> Self {
+        Self {
+            style,
+            local: false,
+        }
+    }
+
+    /// Format a timestamp recorded with the given UTC offset
+    pub fn format(&self, timestamp: i64, offset_minutes: i32, now: i64) -> String {
+        let pattern = match &self.style {
+            DateStyle::Relative => return relative_age(timestamp, now),
+            DateStyle::Unix => return timestamp.to_string(),
+            DateStyle::Raw => return format!("{} {}", timestamp, format_offset(offset_minutes)),
+            DateStyle::Iso => "%Y-%m-%d %H:%M:%S %z",
+            DateStyle::IsoStrict => "%Y-%m-%dT%H:%M:%S%:z",
+            DateStyle::Rfc => "%a, %-d %b %Y %H:%M:%S %z",
+            DateStyle::Short => "%Y-%m-%d",
+            // git leaves the offset out when the date is already local
+            DateStyle::Default if self.local => "%a %b %-d %H:%M:%S %Y",
+            DateStyle::Default => "%a %b %-d %H:%M:%S %Y %z",
+            DateStyle::Custom(pattern) => pattern,
+        };
+
+        let Some(utc) = DateTime::from_timestamp(timestamp, 0) else {
+            return timestamp.to_string();
+        };
+
+        if self.local {
+            utc.with_timezone(&Local).format(pattern).to_string()
+        } else {
+            let offset = FixedOffset::east_opt(offset_minutes * 60)
+                .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+            utc.with_timezone(&offset).format(pattern).to_string()
+        }
+    }
+
+    /// Typical display width, used to size date columns
+    pub fn width(&self) -> usize {
+        match self.style {
+            DateStyle::Relative => "11 months ago".len(),
+            // Two-digit days and a negative offset give the longest output
+            _ => self.format(977_923_999, -570, 0).chars().count(),
+        }
+    }
+}
+
+pub fn format_offset(offset_minutes: i32) -> String {
+    let sign = if offset_minutes < 0 { '-' } else { '+' };
+    let minutes = offset_minutes.abs();
+    format!("{}{:02}{:02}", sign, minutes / 60, minutes % 60)
+}
+
+impl FromStr for DateFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(pattern) = s.strip_prefix("format-local:") {
+            return Ok(Self {
+                style: custom_style(pattern)?,
+                local: true,
+            });
+        }
+        if let Some(pattern) = s.strip_prefix("format:") {
+            return Ok(Self::new(custom_style(pattern)?));
+        }
+
+        let (name, local) = match s.strip_suffix("-local") {
+            Some(name) => (name, true),
+            None if s == "local" => ("default", true),
+            None => (s, false),
+        };
+
+        let style = match name {
+            "relative" => DateStyle::Relative,
+            "iso" | "iso8601" => DateStyle::Iso,
+            "iso-strict" | "iso8601-strict" => DateStyle::IsoStrict,
+            "rfc" | "rfc2822" => DateStyle::Rfc,
+            "short" => DateStyle::Short,
+            "default" => DateStyle::Default,
+            "unix" => DateStyle::Unix,
+            "raw" => DateStyle::Raw,
+            _ => return Err(format!("Unknown date format: {}", s)),
+        };
+
+        Ok(Self { style, local })
+    }
+}
+
+/// Reject patterns chrono cannot format, since formatting them would panic
+fn custom_style(pattern: &str) -> Result<DateStyle, String> {
+    if StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error)) {
+        return Err(format!("Invalid date pattern: {}", pattern));
+    }
+    Ok(DateStyle::Custom(pattern.to_string()))
+}
+
+impl fmt::Display for DateFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match &self.style {
+            DateStyle::Custom(pattern) if self.local => {
+                return write!(f, "format-local:{}", pattern);
+            }
+            DateStyle::Custom(pattern) => return write!(f, "format:{}", pattern),
+            DateStyle::Relative => "relative",
+            DateStyle::Iso => "iso",
+            DateStyle::IsoStrict => "iso-strict",
+            DateStyle::Rfc => "rfc",
+            DateStyle::Short => "short",
+            DateStyle::Default => "default",
+            DateStyle::Unix => "unix",
+            DateStyle::Raw => "raw",
+        };
+
+        if self.local {
+            write!(f, "{}-local", name)
+        } else {
+            write!(f, "{}", name)
+        }
+    }
+}
+
+impl Serialize for DateFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for DateFormat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // 2020-09-13 12:26:40 UTC
+    const TS: i64 = 1_600_000_000;
+
+    fn format(spec: &str, timestamp: i64, offset_minutes: i32) -> String {
+        spec.parse::<DateFormat>()
+            .unwrap()
+            .format(timestamp, offset_minutes, TS)
+    }
+
+    #[test]
+    fn relative_age_picks_largest_sensible_unit() {
+        let now = 1_000_000_000;
+        assert_eq!(relative_age(now - 10, now), "just now");
+        assert_eq!(relative_age(now - 120, now), "2 minutes ago");
+        assert_eq!(relative_age(now - 3600, now), "1 hour ago");
+        assert_eq!(relative_age(now - 3 * 86400, now), "3 days ago");
+        assert_eq!(relative_age(now - 21 * 86400, now), "3 weeks ago");
+        assert_eq!(relative_age(now - 90 * 86400, now), "3 months ago");
+        assert_eq!(relative_age(now - 800 * 86400, now), "2 years ago");
+        assert_eq!(relative_age(now + 10, now), "in the future");
+    }
+
+    #[test]
+    fn formats_in_the_signature_timezone() {
+        assert_eq!(format("iso", TS, 120), "2020-09-13 14:26:40 +0200");
+        assert_eq!(format("iso-strict", TS, -300), "2020-09-13T07:26:40-05:00");
+        assert_eq!(format("short", TS, -720), "2020-09-13");
+        assert_eq!(format("short", TS, 720), "2020-09-14");
+        assert_eq!(format("default", TS, 0), "Sun Sep 13 12:26:40 2020 +0000");
+        assert_eq!(format("raw", TS, -90), "1600000000 -0130");
+        assert_eq!(format("relative", TS - 86400, 0), "1 day ago");
+    }
+
+    #[test]
+    fn formats_custom_patterns() {
+        assert_eq!(format("format:%d/%m/%Y %H:%M", TS, 60), "13/09/2020 13:26");
+    }
+
+    #[test]
+    fn formats_pre_epoch_timestamps() {
+        assert_eq!(format("iso", -86400, 0), "1969-12-31 00:00:00 +0000");
+        assert_eq!(format("short", -1_000_000_000, 0), "1938-04-24");
+    }
+
+    #[test]
+    fn parses_git_date_names() {
+        let local: DateFormat = "iso-local".parse().unwrap();
+        assert_eq!(local.style, DateStyle::Iso);
+        assert!(local.local);
+
+        let local: DateFormat = "local".parse().unwrap();
+        assert_eq!(local.style, DateStyle::Default);
+        assert!(local.local);
+
+        assert!("format:%Q".parse::<DateFormat>().is_err());
+        assert!("someday".parse::<DateFormat>().is_err());
+    }
+
+    #[test]
+    fn width_matches_formatted_length() {
+        assert_eq!(DateFormat::new(DateStyle::Short).width(), 10);
+        assert_eq!(DateFormat::new(DateStyle::Iso).width(), 25);
+    }
+
+    proptest! {
+        #[test]
+        fn relative_age_handles_any_past_timestamp(ts in i64::MIN / 2..0i64) {
+            prop_assert!(relative_age(ts, 0).ends_with("ago"));
+        }
+
+        #[test]
+        fn display_roundtrips_through_parse(
+            name in "(relative|iso|iso-strict|rfc|short|default|unix|raw)(-local)?"
+        ) {
+            let format: DateFormat = name.parse().unwrap();
+            prop_assert_eq!(format.to_string().parse::<DateFormat>().unwrap(), format);
+        }
+
+        #[test]
+        fn format_never_panics(ts in any::<i64>(), offset in -1440i32..1440) {
+            let _ = DateFormat::new(DateStyle::Iso).format(ts, offset, 0);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 #[derive(Debug, Clone)]
 pub struct Diff(String);
 
@@ -9,6 +11,50 @@ impl Diff {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// The hunk of `path` whose new side covers `line` (1-based), including its `@@` header
+    pub fn hunk(&self, path: &Path, line: usize) -> Option<String> {
+        let target = format!(" b/{}", path.to_string_lossy());
+        let mut in_file = false;
+        let mut hunk: Option<(Vec<&str>, bool)> = None;
+
+        for text in self.0.lines() {
+            if text.starts_with("diff --git ") {
+                if let Some((lines, true)) = hunk.take() {
+                    return Some(lines.join("\n"));
+                }
+                in_file = text.ends_with(&target);
+                continue;
+            }
+            if !in_file {
+                continue;
+            }
+            if let Some(header) = text.strip_prefix("@@ ") {
+                if let Some((lines, true)) = hunk.take() {
+                    return Some(lines.join("\n"));
+                }
+                let covers = new_range(header)
+                    .is_some_and(|(start, count)| line >= start && line < start + count.max(1));
+                hunk = Some((vec![text], covers));
+            } else if let Some((lines, _)) = hunk.as_mut() {
+                lines.push(text);
+            }
+        }
+
+        match hunk {
+            Some((lines, true)) => Some(lines.join("\n")),
+            _ => None,
+        }
+    }
+}
+
+/// Start and length of the new side from a hunk header such as `-1,4 +2,5 @@`
+fn new_range(header: &str) -> Option<(usize, usize)> {
+    let new = header
+        .split_whitespace()
+        .find_map(|s| s.strip_prefix('+'))?;
+    let (start, count) = new.split_once(',').unwrap_or((new, "1"));
+    Some((start.parse().ok()?, count.parse().ok()?))
 }
 
 #[cfg(test)]
@@ -16,6 +62,44 @@ mod tests {
     use super::*;
     use proptest::prelude::*;
 
+    const DIFF: &str = "\
+diff --git a/src/a.rs b/src/a.rs
+index 1111111..2222222 100644
+--- a/src/a.rs
++++ b/src/a.rs
+@@ -1,2 +1,3 @@
+ fn a() {
++    one();
+ }
+@@ -10,2 +11,3 @@ fn b() {
+ fn b() {
++    two();
+ }
+diff --git a/src/b.rs b/src/b.rs
+@@ -1 +1 @@
+-old
++new
+";
+
+    #[test]
+    fn hunk_finds_the_covering_hunk() {
+        let diff = Diff::new(DIFF);
+        let first = diff.hunk(Path::new("src/a.rs"), 2).unwrap();
+        assert!(first.starts_with("@@ -1,2 +1,3 @@"));
+        assert!(first.ends_with(" }"));
+        assert!(!first.contains("two"));
+
+        let second = diff.hunk(Path::new("src/a.rs"), 12).unwrap();
+        assert!(second.contains("+    two();"));
+
+        assert_eq!(
+            diff.hunk(Path::new("src/b.rs"), 1).unwrap(),
+            "@@ -1 +1 @@\n-old\n+new"
+        );
+        assert!(diff.hunk(Path::new("src/a.rs"), 5).is_none());
+        assert!(diff.hunk(Path::new("src/c.rs"), 1).is_none());
+    }
+
     proptest! {
         #[test]
         fn new_and_as_str_roundtrip(s in "(?s).{0,1000}") {
@@ -11,5 +11,18 @@ pub trait GitGateway {
 
     fn commit_info(&self, commit: &CommitHash) -> Result<CommitInfo, Self::Error>;
 
-    fn github_commit_url(&self, commit: &CommitHash) -> Option<String>;
+    /// Full message of a commit, without the rest of `commit_info`
+    fn commit_message(&self, commit: &CommitHash) -> Result<String, Self::Error>;
+
+    /// Contents of a file at a revision
+    fn file_at(&self, commit: &CommitHash, path: &Path) -> Result<Vec<u8>, Self::Error>;
+
+    /// The commit checked out in the working tree
+    fn head(&self) -> Option<CommitHash>;
+
+    /// Root of the working tree, unless the repository is bare
+    fn workdir(&self) -> Option<&Path>;
+
+    /// Names and URLs of the repository's remotes
+    fn remotes(&self) -> Vec<(String, String)>;
 }
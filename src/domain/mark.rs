@@ -0,0 +1,54 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// A named line bookmark (`m{a-z}`), valid across revisions of the trail
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Mark {
+    pub name: char,
+    pub path: PathBuf,
+    pub revision: String,
+    /// 1-based line number in `path` at `revision`
+    pub line: usize,
+}
+
+impl Mark {
+    /// Whether `name` can be used for a mark
+    pub fn is_valid_name(name: char) -> bool {
+        name.is_ascii_lowercase()
+    }
+
+    /// Insert or replace the mark with the same name, keeping marks sorted by name
+    pub fn set(marks: &mut Vec<Mark>, mark: Mark) {
+        match marks.binary_search_by_key(&mark.name, |m| m.name) {
+            Ok(index) => marks[index] = mark,
+            Err(index) => marks.insert(index, mark),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mark(name: char, line: usize) -> Mark {
+        Mark {
+            name,
+            path: "src/a.rs".into(),
+            revision: "abc1234".to_string(),
+            line,
+        }
+    }
+
+    #[test]
+    fn set_keeps_marks_sorted_and_unique() {
+        let mut marks = Vec::new();
+        Mark::set(&mut marks, mark('c', 1));
+        Mark::set(&mut marks, mark('a', 2));
+        Mark::set(&mut marks, mark('c', 3));
+
+        assert_eq!(marks, vec![mark('a', 2), mark('c', 3)]);
+        assert!(Mark::is_valid_name('z'));
+        assert!(!Mark::is_valid_name('A'));
+    }
+}
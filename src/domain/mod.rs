@@ -1,9 +1,16 @@
 pub mod blame;
 pub mod commit;
+pub mod date;
 pub mod diff;
 pub mod gateway;
+pub mod mark;
+pub mod session;
+pub mod stats;
 
-pub use blame::{BlameEntry, BlameFrame, BlameStack};
-pub use commit::{CommitHash, CommitInfo};
+pub use blame::{BlameEntry, BlameFrame, BlameStack, HistoryBoundary};
+pub use commit::{CommitHash, CommitInfo, Signature};
 pub use diff::Diff;
 pub use gateway::GitGateway;
+pub use mark::Mark;
+pub use session::Session;
+pub use stats::{Owner, OwnershipStats};
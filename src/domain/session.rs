@@ -0,0 +1,89 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::BlameStack;
+
+/// A saved exploration: enough to rebuild every frame of a `BlameStack`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Session {
+    /// Repository-relative file the exploration started from; sessions are keyed by it
+    pub file: PathBuf,
+    /// Unix timestamp of the last save
+    pub saved_at: i64,
+    pub frames: Vec<SessionFrame>,
+}
+
+/// Position within one frame of a saved session
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SessionFrame {
+    pub revision: String,
+    pub path: PathBuf,
+    pub selected_line: usize,
+    pub scroll_offset: usize,
+    /// See `BlameFrame::drilled_through`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drilled_through: Option<String>,
+}
+
+impl Session {
+    /// Snapshot a stack, or `None` if it has no frames
+    pub fn from_stack(stack: &BlameStack, saved_at: i64) -> Option<Self> {
+        let first = stack.frames().first()?;
+        Some(Self {
+            file: first.file_path.clone(),
+            saved_at,
+            frames: stack
+                .frames()
+                .iter()
+                .map(|frame| SessionFrame {
+                    revision: frame.commit_hash.to_string(),
+                    path: frame.file_path.clone(),
+                    selected_line: frame.selected_line,
+                    scroll_offset: frame.scroll_offset,
+                    drilled_through: frame.drilled_through.as_ref().map(|c| c.to_string()),
+                })
+                .collect(),
+        })
+    }
+
+    /// Short revisions of each frame, as "hash1 -> hash2 -> ..."
+    pub fn hash_chain(&self) -> String {
+        let chain: Vec<&str> = self
+            .frames
+            .iter()
+            .map(|f| &f.revision[..7.min(f.revision.len())])
+            .collect();
+        chain.join(" -> ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::BlameFrame;
+
+    #[test]
+    fn snapshots_every_frame() {
+        let mut stack = BlameStack::new();
+        assert!(Session::from_stack(&stack, 0).is_none());
+
+        stack.push(
+            BlameFrame::fixture("aaaaaaaaaa", vec![])
+                .with_path("src/a.rs")
+                .with_position(40, 20),
+        );
+        stack.push(
+            BlameFrame::fixture("bbbbbbbbbb", vec![])
+                .with_path("src/a.rs")
+                .with_position(3, 0),
+        );
+        let session = Session::from_stack(&stack, 1_700_000_000).unwrap();
+
+        assert_eq!(session.file, PathBuf::from("src/a.rs"));
+        assert_eq!(session.frames.len(), 2);
+        assert_eq!(session.frames[0].selected_line, 40);
+        assert_eq!(session.frames[0].scroll_offset, 20);
+        assert_eq!(session.hash_chain(), "aaaaaaa -> bbbbbbb");
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use crate::domain::blame::AuthorLines;
+use crate::domain::{BlameEntry, BlameFrame, CommitHash};
+
+const DAY: i64 = 86400;
+
+/// Upper age bounds of the histogram buckets, in days
+const AGE_BUCKETS: [(&str, i64); 6] = [
+    ("< 1 week", 7),
+    ("< 1 month", 30),
+    ("< 6 months", 182),
+    ("< 1 year", 365),
+    ("< 2 years", 730),
+    (">= 2 years", i64::MAX),
+];
+
+/// Lines last changed by one commit in a frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitLines {
+    pub hash: CommitHash,
+    pub author: String,
+    pub summary: String,
+    pub timestamp: i64,
+    pub lines: usize,
+}
+
+/// Lines whose age falls in one histogram bucket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgeBucket {
+    pub label: &'static str,
+    pub lines: usize,
+}
+
+/// Who owns the lines of a frame and how old they are
+#[derive(Debug, Clone)]
+pub struct OwnershipStats {
+    pub total_lines: usize,
+    /// Most lines first
+    pub authors: Vec<AuthorLines>,
+    /// Most lines first
+    pub commits: Vec<CommitLines>,
+    pub ages: Vec<AgeBucket>,
+    /// Index of the oldest line
+    pub oldest: Option<usize>,
+    /// Index of the newest line
+    pub newest: Option<usize>,
+}
+
+impl OwnershipStats {
+    pub fn new(frame: &BlameFrame, now: i64) -> Self {
+        let mut commits: Vec<CommitLines> = Vec::new();
+        // Position of each commit in `commits`
+        let mut commit_index: HashMap<&CommitHash, usize> = HashMap::new();
+        let mut ages: Vec<AgeBucket> = AGE_BUCKETS
+            .iter()
+            .map(|&(label, _)| AgeBucket { label, lines: 0 })
+            .collect();
+
+        for entry in &frame.entries {
+            match commit_index.get(&entry.commit_hash) {
+                Some(&index) => commits[index].lines += 1,
+                None => {
+                    commit_index.insert(&entry.commit_hash, commits.len());
+                    commits.push(CommitLines {
+                        hash: entry.commit_hash.clone(),
+                        author: entry.author.clone(),
+                        summary: entry.summary.clone(),
+                        timestamp: entry.timestamp,
+                        lines: 1,
+                    });
+                }
+            }
+
+            let age_days = (now - entry.timestamp).max(0) / DAY;
+            let bucket = AGE_BUCKETS
+                .iter()
+                .position(|&(_, max_days)| age_days < max_days)
+                .unwrap_or(AGE_BUCKETS.len() - 1);
+            ages[bucket].lines += 1;
+        }
+        commits.sort_by(|a, b| b.lines.cmp(&a.lines).then(b.timestamp.cmp(&a.timestamp)));
+
+        let by_age =
+            |a: &(usize, &BlameEntry), b: &(usize, &BlameEntry)| a.1.timestamp.cmp(&b.1.timestamp);
+        // Ties go to the first line in the file (`min_by` keeps the first, `max_by` the last)
+        let oldest = frame.entries.iter().enumerate().min_by(by_age);
+        let newest = frame.entries.iter().enumerate().rev().max_by(by_age);
+
+        Self {
+            total_lines: frame.entries.len(),
+            authors: frame.authors(),
+            commits,
+            ages,
+            oldest: oldest.map(|(index, _)| index),
+            newest: newest.map(|(index, _)| index),
+        }
+    }
+
+    /// Share of the frame's lines, from 0.0 to 100.0
+    pub fn percent(&self, lines: usize) -> f64 {
+        if self.total_lines == 0 {
+            0.0
+        } else {
+            lines as f64 * 100.0 / self.total_lines as f64
+        }
+    }
+
+    /// Selectable owners in display order: authors, then commits
+    pub fn owners(&self) -> Vec<Owner> {
+        self.authors
+            .iter()
+            .map(|a| Owner::Author(a.key.clone()))
+            .chain(self.commits.iter().map(|c| Owner::Commit(c.hash.clone())))
+            .collect()
+    }
+}
+
+/// An author or commit whose lines can be highlighted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Owner {
+    /// See `BlameEntry::author_key`
+    Author(String),
+    Commit(CommitHash),
+}
+
+impl Owner {
+    pub fn owns(&self, entry: &BlameEntry) -> bool {
+        match self {
+            Owner::Author(key) => entry.author_key() == *key,
+            Owner::Commit(hash) => entry.commit_hash == *hash,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn entry(hash: &str, author: &str, age_days: i64) -> BlameEntry {
+        BlameEntry::fixture(hash, author).with_time(NOW - age_days * DAY, 0)
+    }
+
+    #[test]
+    fn counts_lines_per_commit_and_age() {
+        let stats = OwnershipStats::new(
+            &BlameFrame::fixture(
+                "head",
+                vec![
+                    entry("aaa", "Alice", 800),
+                    entry("bbb", "Bob", 2),
+                    entry("bbb", "Bob", 2),
+                    entry("ccc", "Alice", 100),
+                ],
+            ),
+            NOW,
+        );
+
+        assert_eq!(stats.total_lines, 4);
+        assert_eq!(stats.commits.len(), 3);
+        assert_eq!(stats.commits[0].hash, CommitHash::new("bbb"));
+        assert_eq!(stats.commits[0].lines, 2);
+        assert_eq!(stats.authors[0].author, "Alice");
+        assert_eq!(stats.percent(stats.authors[0].lines), 50.0);
+
+        let ages: Vec<usize> = stats.ages.iter().map(|b| b.lines).collect();
+        assert_eq!(ages, vec![2, 0, 1, 0, 0, 1]);
+        assert_eq!(stats.oldest, Some(0));
+        assert_eq!(stats.newest, Some(1));
+    }
+
+    #[test]
+    fn owners_match_their_lines() {
+        let alice = entry("aaa", "Alice", 1);
+        let bob = entry("bbb", "Bob", 1);
+
+        let author = Owner::Author(alice.author_key());
+        assert!(author.owns(&alice));
+        assert!(!author.owns(&bob));
+        assert!(Owner::Commit(CommitHash::new("bbb")).owns(&bob));
+    }
+
+    #[test]
+    fn empty_frame_has_no_extremes() {
+        let stats = OwnershipStats::new(&BlameFrame::fixture("head", vec![]), NOW);
+        assert_eq!(stats.oldest, None);
+        assert_eq!(stats.percent(0), 0.0);
+        assert!(stats.owners().is_empty());
+    }
+}
@@ -0,0 +1,170 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::config::ExportFormat;
+use crate::domain::date::{self, DateFormat};
+use crate::domain::{BlameStack, GitGateway};
+
+/// One frame of the exploration trail and the commit behind its tracked line
+#[derive(Debug, Clone, Serialize)]
+pub struct TrailStep {
+    pub path: PathBuf,
+    pub revision: String,
+    pub line: usize,
+    pub content: String,
+    pub commit: String,
+    pub author: String,
+    pub author_email: String,
+    pub author_time: i64,
+    /// Offset from UTC in minutes
+    pub author_offset: i32,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hunk: Option<String>,
+}
+
+/// Collect the trail from the starting revision to the current frame
+pub fn trail<G: GitGateway>(
+    git: &G,
+    stack: &BlameStack,
+    include_diff: bool,
+) -> Result<Vec<TrailStep>> {
+    let mut steps = Vec::new();
+    for frame in stack.frames() {
+        let Some(entry) = frame.entries.get(frame.selected_line) else {
+            continue;
+        };
+        let info = git.commit_info(&entry.commit_hash)?;
+        let original_path = entry.original_path.as_deref().unwrap_or(&frame.file_path);
+        let hunk = if include_diff {
+            git.diff(&entry.commit_hash)?
+                .hunk(original_path, entry.original_line_number)
+        } else {
+            None
+        };
+
+        steps.push(TrailStep {
+            path: frame.file_path.clone(),
+            revision: frame.commit_hash.to_string(),
+            line: entry.line_number,
+            content: entry.content.clone(),
+            commit: entry.commit_hash.to_string(),
+            author: entry.author.clone(),
+            author_email: entry.author_email.clone(),
+            author_time: entry.timestamp,
+            author_offset: entry.offset_minutes,
+            message: info.message.trim_end().to_string(),
+            hunk,
+        });
+    }
+    Ok(steps)
+}
+
+/// Render a trail report, ending with a newline; Markdown dates use `date_format`
+pub fn render(
+    steps: &[TrailStep],
+    format: ExportFormat,
+    date_format: &DateFormat,
+) -> Result<String> {
+    match format {
+        ExportFormat::Markdown => Ok(markdown(steps, date_format)),
+        ExportFormat::Json => Ok(serde_json::to_string_pretty(steps)? + "\n"),
+    }
+}
+
+fn markdown(steps: &[TrailStep], date_format: &DateFormat) -> String {
+    let now = date::now();
+    let mut out = String::from("# Blame trail\n");
+
+    for (i, step) in steps.iter().enumerate() {
+        let subject = step.message.lines().next().unwrap_or_default();
+        let _ = write!(
+            out,
+            "\n## {}. {} {}\n\n\
+             - File: `{}` at `{}`\n\
+             - Line {}: `{}`\n\
+             - Author: {} <{}>\n\
+             - Date: {}\n",
+            i + 1,
+            &step.commit[..7.min(step.commit.len())],
+            subject,
+            step.path.display(),
+            &step.revision[..7.min(step.revision.len())],
+            step.line,
+            step.content.trim(),
+            step.author,
+            step.author_email,
+            date_format.format(step.author_time, step.author_offset, now),
+        );
+
+        let body = step
+            .message
+            .split_once('\n')
+            .map_or("", |(_, body)| body.trim());
+        if !body.is_empty() {
+            let _ = write!(out, "\n{}\n", body);
+        }
+        if let Some(hunk) = &step.hunk {
+            let _ = write!(out, "\n```diff\n{}\n```\n", hunk);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::date::DateStyle;
+
+    fn step(hunk: Option<&str>) -> TrailStep {
+        TrailStep {
+            path: "src/a.rs".into(),
+            revision: "1234567890".to_string(),
+            line: 12,
+            content: "    run();".to_string(),
+            commit: "abcdef0123".to_string(),
+            author: "Alice".to_string(),
+            author_email: "alice@example.com".to_string(),
+            author_time: 1_700_000_000,
+            author_offset: 60,
+            message: "Call run\n\nIt was missing.".to_string(),
+            hunk: hunk.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn markdown_lists_each_step() {
+        let out = render(
+            &[step(Some("@@ -1 +1 @@\n+    run();")), step(None)],
+            ExportFormat::Markdown,
+            &DateFormat::new(DateStyle::Iso),
+        )
+        .unwrap();
+
+        assert!(out.starts_with("# Blame trail\n"));
+        assert!(out.contains("## 1. abcdef0 Call run\n"));
+        assert!(out.contains("## 2. abcdef0 Call run\n"));
+        assert!(out.contains("- File: `src/a.rs` at `1234567`\n"));
+        assert!(out.contains("- Line 12: `run();`\n"));
+        assert!(out.contains("- Date: 2023-11-14 23:13:20 +0100\n"));
+        assert!(out.contains("\nIt was missing.\n"));
+        assert_eq!(out.matches("```diff").count(), 1);
+    }
+
+    #[test]
+    fn json_omits_missing_hunks() {
+        let out = render(
+            &[step(None)],
+            ExportFormat::Json,
+            &DateFormat::new(DateStyle::Iso),
+        )
+        .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value[0]["line"], 12);
+        assert_eq!(value[0]["message"], "Call run\n\nIt was missing.");
+        assert!(value[0].get("hunk").is_none());
+    }
+}
@@ -0,0 +1,153 @@
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use thiserror::Error;
+
+use crate::application::port::Clipboard;
+use crate::config::{ClipboardConfig, ClipboardMethod};
+
+/// Terminals commonly drop OSC 52 payloads larger than this many encoded bytes
+const OSC52_MAX_ENCODED: usize = 100_000;
+
+#[derive(Debug, Error)]
+pub enum ClipboardError {
+    #[error("no copy command configured in [clipboard]")]
+    NoCommand,
+
+    #[error("text is too large for OSC 52; configure a [clipboard] command")]
+    TooLarge,
+
+    #[error("copy command failed: {0}")]
+    Command(String),
+
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// Copies through the terminal with OSC 52, or through an external command
+pub struct SystemClipboard {
+    config: ClipboardConfig,
+}
+
+impl SystemClipboard {
+    pub fn new(config: ClipboardConfig) -> Self {
+        Self { config }
+    }
+
+    fn use_command(&self) -> bool {
+        match self.config.method {
+            ClipboardMethod::Osc52 => false,
+            ClipboardMethod::Command => true,
+            // A local command can't reach the clipboard of the machine an SSH user sits at
+            ClipboardMethod::Auto => {
+                self.config.command.is_some()
+                    && std::env::var_os("SSH_CONNECTION").is_none()
+                    && std::env::var_os("SSH_TTY").is_none()
+            }
+        }
+    }
+
+    fn run_command(&self, text: &str) -> Result<(), ClipboardError> {
+        let (program, args) = self
+            .config
+            .command
+            .as_deref()
+            .and_then(|c| c.split_first())
+            .ok_or(ClipboardError::NoCommand)?;
+
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(text.as_bytes())?;
+        }
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(ClipboardError::Command(stderr.trim().to_string()));
+        }
+        Ok(())
+    }
+}
+
+impl Clipboard for SystemClipboard {
+    type Error = ClipboardError;
+
+    fn copy(&self, text: &str) -> Result<(), Self::Error> {
+        if self.use_command() {
+            return self.run_command(text);
+        }
+
+        let sequence = osc52(text, std::env::var_os("TMUX").is_some());
+        if sequence.len() > OSC52_MAX_ENCODED {
+            return match self.config.command {
+                Some(_) => self.run_command(text),
+                None => Err(ClipboardError::TooLarge),
+            };
+        }
+        let mut stdout = io::stdout();
+        stdout.write_all(sequence.as_bytes())?;
+        stdout.flush()?;
+        Ok(())
+    }
+}
+
+/// OSC 52 escape setting the clipboard, wrapped in tmux's passthrough when needed
+fn osc52(text: &str, tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", STANDARD.encode(text));
+    if tmux {
+        // tmux forwards DCS passthrough content with each ESC doubled
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_osc52() {
+        assert_eq!(osc52("abc1234", false), "\x1b]52;c;YWJjMTIzNA==\x07");
+        assert_eq!(
+            osc52("abc1234", true),
+            "\x1bPtmux;\x1b\x1b]52;c;YWJjMTIzNA==\x07\x1b\\"
+        );
+    }
+
+    #[test]
+    fn command_receives_text() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("copied");
+        let clipboard = SystemClipboard::new(ClipboardConfig {
+            method: ClipboardMethod::Command,
+            command: Some(vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                format!("cat > {}", out.display()),
+            ]),
+        });
+
+        clipboard.copy("deadbeef").unwrap();
+        assert_eq!(std::fs::read_to_string(out).unwrap(), "deadbeef");
+    }
+
+    #[test]
+    fn command_method_needs_a_command() {
+        let clipboard = SystemClipboard::new(ClipboardConfig {
+            method: ClipboardMethod::Command,
+            command: None,
+        });
+        assert!(matches!(
+            clipboard.copy("x"),
+            Err(ClipboardError::NoCommand)
+        ));
+    }
+}
@@ -0,0 +1,65 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::config::EditorConfig;
+
+/// Launches the user's editor at a line of a file
+pub struct Editor {
+    program: Vec<String>,
+    args: Vec<String>,
+}
+
+impl Editor {
+    /// Use the configured command, then `$VISUAL`, then `$EDITOR`, falling back to `vi`
+    pub fn new(config: &EditorConfig) -> Self {
+        let program = config
+            .command
+            .clone()
+            .filter(|command| !command.is_empty())
+            .unwrap_or_else(|| {
+                ["VISUAL", "EDITOR"]
+                    .iter()
+                    .filter_map(|var| std::env::var(var).ok())
+                    .map(|value| value.split_whitespace().map(str::to_string).collect())
+                    .find(|words: &Vec<String>| !words.is_empty())
+                    .unwrap_or_else(|| vec!["vi".to_string()])
+            });
+        Self {
+            program,
+            args: config.args.clone(),
+        }
+    }
+
+    pub fn command(&self, file: &Path, line: usize) -> Command {
+        let file = file.to_string_lossy();
+        let line = line.to_string();
+        let (program, program_args) = self.program.split_first().expect("editor command is set");
+
+        let mut command = Command::new(program);
+        command.args(program_args);
+        command.args(
+            self.args
+                .iter()
+                .map(|arg| arg.replace("{file}", &file).replace("{line}", &line)),
+        );
+        command
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_the_line_template() {
+        let editor = Editor::new(&EditorConfig {
+            command: Some(vec!["code".to_string(), "--wait".to_string()]),
+            args: vec!["--goto".to_string(), "{file}:{line}".to_string()],
+        });
+        let command = editor.command(Path::new("/repo/src/main.rs"), 42);
+
+        assert_eq!(command.get_program(), "code");
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(args, ["--wait", "--goto", "/repo/src/main.rs:42"]);
+    }
+}
@@ -1,8 +1,13 @@
-use git2::Repository;
+use git2::{Mailmap, Oid, Repository};
+use std::cell::OnceCell;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 use std::path::Path;
 use thiserror::Error;
 
-use crate::domain::{BlameEntry, BlameFrame, CommitHash, CommitInfo, Diff, GitGateway};
+use crate::domain::{
+    BlameEntry, BlameFrame, CommitHash, CommitInfo, Diff, GitGateway, HistoryBoundary, Signature,
+};
 
 #[derive(Debug, Error)]
 pub enum GitError {
@@ -12,18 +17,146 @@ pub enum GitError {
 
 pub struct Git2Gateway {
     repo: Repository,
+    oldest_commit: Option<Oid>,
+    mailmap: Option<Mailmap>,
+    /// Branch and tag names by the commit they point at, collected on first use
+    decorations: OnceCell<HashMap<Oid, Vec<String>>>,
 }
 
 impl Git2Gateway {
     #[allow(dead_code)]
     pub fn open(path: &Path) -> Result<Self, GitError> {
         let repo = Repository::discover(path)?;
-        Ok(Self { repo })
+        Ok(Self {
+            repo,
+            oldest_commit: None,
+            mailmap: None,
+            decorations: OnceCell::new(),
+        })
     }
 
     pub fn open_current() -> Result<Self, GitError> {
         let repo = Repository::discover(".")?;
-        Ok(Self { repo })
+        Ok(Self {
+            repo,
+            oldest_commit: None,
+            mailmap: None,
+            decorations: OnceCell::new(),
+        })
+    }
+
+    /// Resolve author names through `.mailmap`, `mailmap.file` and `mailmap.blob`
+    pub fn with_mailmap(mut self, enabled: bool) -> Result<Self, GitError> {
+        self.mailmap = if enabled {
+            Some(self.repo.mailmap()?)
+        } else {
+            None
+        };
+        Ok(self)
+    }
+
+    /// Convert a signature, resolving it through the mailmap when enabled
+    fn signature(&self, sig: &git2::Signature) -> Result<Signature, GitError> {
+        let raw_name = sig.name().unwrap_or("Unknown").to_string();
+        let resolved = match &self.mailmap {
+            Some(mailmap) => mailmap.resolve_signature(sig)?,
+            None => sig.to_owned(),
+        };
+        let name = resolved.name().unwrap_or("Unknown").to_string();
+
+        Ok(Signature {
+            raw_name: (name != raw_name).then_some(raw_name),
+            name,
+            email: resolved.email().unwrap_or("").to_string(),
+            timestamp: sig.when().seconds(),
+            offset_minutes: sig.when().offset_minutes(),
+        })
+    }
+
+    /// Branch and tag names that point at the given commit
+    fn decorations(&self, commit: Oid) -> Result<Vec<String>, GitError> {
+        let refs = match self.decorations.get() {
+            Some(refs) => refs,
+            None => {
+                let refs = self.collect_decorations()?;
+                self.decorations.get_or_init(|| refs)
+            }
+        };
+        Ok(refs.get(&commit).cloned().unwrap_or_default())
+    }
+
+    /// Peel every branch and tag once, grouping their names by commit
+    fn collect_decorations(&self) -> Result<HashMap<Oid, Vec<String>>, GitError> {
+        let mut refs: HashMap<Oid, Vec<String>> = HashMap::new();
+        for reference in self.repo.references()? {
+            let reference = reference?;
+            if !(reference.is_branch() || reference.is_remote() || reference.is_tag()) {
+                continue;
+            }
+            let Ok(target) = reference.peel_to_commit() else {
+                continue;
+            };
+            if let Some(name) = reference.shorthand() {
+                let name = if reference.is_tag() {
+                    format!("tag: {}", name)
+                } else {
+                    name.to_string()
+                };
+                refs.entry(target.id()).or_default().push(name);
+            }
+        }
+        for names in refs.values_mut() {
+            names.sort();
+        }
+        Ok(refs)
+    }
+
+    /// The repository's `blame.date` setting, if any
+    pub fn blame_date_format(&self) -> Option<String> {
+        self.repo.config().ok()?.get_string("blame.date").ok()
+    }
+
+    /// Limit blame history so lines older than the boundary are attributed to it
+    pub fn with_history_boundary(
+        mut self,
+        boundary: &HistoryBoundary,
+        newest: &CommitHash,
+    ) -> Result<Self, GitError> {
+        self.oldest_commit = match boundary {
+            HistoryBoundary::Commit(commit) => Some(
+                self.repo
+                    .revparse_single(commit.as_str())?
+                    .peel_to_commit()?
+                    .id(),
+            ),
+            HistoryBoundary::Since(since) => self.first_commit_before(newest, *since)?,
+        };
+        Ok(self)
+    }
+
+    /// Walk history from `newest` and return the first commit older than `since`
+    fn first_commit_before(
+        &self,
+        newest: &CommitHash,
+        since: i64,
+    ) -> Result<Option<Oid>, GitError> {
+        let newest = self
+            .repo
+            .revparse_single(newest.as_str())?
+            .peel_to_commit()?;
+
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.set_sorting(git2::Sort::TIME)?;
+        revwalk.push(newest.id())?;
+
+        for oid in revwalk {
+            let oid = oid?;
+            if self.repo.find_commit(oid)?.time().seconds() < since {
+                return Ok(Some(oid));
+            }
+        }
+
+        Ok(None)
     }
 }
 
@@ -44,28 +177,54 @@ impl GitGateway for Git2Gateway {
 
         let mut opts = git2::BlameOptions::new();
         opts.newest_commit(commit_oid);
+        if let Some(oldest) = self.oldest_commit {
+            opts.oldest_commit(oldest);
+        }
 
         let blame = self.repo.blame_file(relative_path, Some(&mut opts))?;
 
+        let blob = commit_obj
+            .tree()?
+            .get_path(relative_path)?
+            .to_object(&self.repo)?;
+        let content = blob
+            .as_blob()
+            .map(|b| String::from_utf8_lossy(b.content()).to_string())
+            .unwrap_or_default();
+        let lines: Vec<&str> = content.lines().collect();
+
+        // Several hunks usually share a commit, so look each summary up once
+        let mut summaries: HashMap<Oid, String> = HashMap::new();
+
         let mut entries = Vec::new();
+        let mut hunk_starts = HashSet::new();
         for hunk in blame.iter() {
-            let sig = hunk.final_signature();
-            let author = sig.name().unwrap_or("Unknown").to_string();
-            let timestamp = sig.when().seconds();
+            let sig = self.signature(&hunk.final_signature())?;
             let hunk_commit = hunk.final_commit_id();
+            // libgit2 also flags root commits as boundaries; only the configured limit counts here
+            let boundary = self.oldest_commit == Some(hunk_commit);
+            let summary = match summaries.get(&hunk_commit) {
+                Some(summary) => summary.clone(),
+                None => {
+                    let summary = self
+                        .repo
+                        .find_commit(hunk_commit)?
+                        .summary()
+                        .unwrap_or("")
+                        .to_string();
+                    summaries.insert(hunk_commit, summary.clone());
+                    summary
+                }
+            };
+            let original_path = hunk
+                .path()
+                .filter(|p| *p != relative_path)
+                .map(Path::to_path_buf);
 
-            let blob = commit_obj
-                .tree()?
-                .get_path(relative_path)?
-                .to_object(&self.repo)?;
-            let content = blob
-                .as_blob()
-                .map(|b| String::from_utf8_lossy(b.content()).to_string())
-                .unwrap_or_default();
-
-            let lines: Vec<&str> = content.lines().collect();
             let start_line = hunk.final_start_line();
+            let orig_start_line = hunk.orig_start_line();
             let line_count = hunk.lines_in_hunk();
+            hunk_starts.insert(start_line);
 
             for line_offset in 0..line_count {
                 let line_number = start_line + line_offset;
@@ -78,20 +237,39 @@ impl GitGateway for Git2Gateway {
                 entries.push(BlameEntry {
                     line_number,
                     commit_hash: CommitHash::new(hunk_commit.to_string()),
-                    author: author.clone(),
-                    timestamp,
+                    author: sig.name.clone(),
+                    raw_author: sig.raw_name.clone(),
+                    author_email: sig.email.clone(),
+                    timestamp: sig.timestamp,
+                    offset_minutes: sig.offset_minutes,
+                    summary: summary.clone(),
                     content: line_content,
+                    original_line_number: orig_start_line + line_offset,
+                    original_path: original_path.clone(),
+                    boundary,
                 })
             }
         }
 
         entries.sort_by_key(|e| e.line_number);
 
+        // Keep libgit2's hunk boundaries so the view can group lines by hunk
+        let mut hunks: Vec<Range<usize>> = Vec::new();
+        for (index, entry) in entries.iter().enumerate() {
+            match hunks.last_mut() {
+                Some(hunk) if !hunk_starts.contains(&entry.line_number) => hunk.end = index + 1,
+                _ => hunks.push(index..index + 1),
+            }
+        }
+
         Ok(BlameFrame {
             file_path: relative_path.to_path_buf(),
             commit_hash: CommitHash::new(commit_oid.to_string()),
             entries,
+            hunks,
             selected_line: 0,
+            scroll_offset: 0,
+            drilled_through: None,
         })
     }
 
@@ -127,43 +305,72 @@ impl GitGateway for Git2Gateway {
         let spec = commit.as_str();
         let commit_obj = self.repo.revparse_single(spec)?.peel_to_commit()?;
 
-        let sig = commit_obj.author();
-        let author = sig.name().unwrap_or("Unknown").to_string();
-        let timestamp = sig.when().seconds();
         let message = commit_obj.message().unwrap_or("").to_string();
-        let parent = commit_obj
-            .parent(0)
-            .ok()
-            .map(|p| CommitHash::new(p.id().to_string()));
+        let parents = commit_obj
+            .parent_ids()
+            .map(|id| CommitHash::new(id.to_string()))
+            .collect();
+        let trailers = git2::message_trailers_strs(&message)
+            .map(|trailers| {
+                trailers
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
 
         Ok(CommitInfo {
             hash: CommitHash::new(commit_obj.id().to_string()),
-            parent,
-            author,
-            timestamp,
+            parents,
+            author: self.signature(&commit_obj.author())?,
+            committer: self.signature(&commit_obj.committer())?,
             message,
+            refs: self.decorations(commit_obj.id())?,
+            trailers,
         })
     }
 
-    fn github_commit_url(&self, commit: &CommitHash) -> Option<String> {
-        // Try to find a GitHub remote
-        let remote = self.repo.find_remote("origin").ok()?;
-        let url = remote.url()?;
-
-        // Parse GitHub URL from remote
-        // Supports: git@github.com:user/repo.git, https://github.com/user/repo.git
-        let github_base = if url.starts_with("git@github.com:") {
-            let path = url.strip_prefix("git@github.com:")?;
-            let path = path.strip_suffix(".git").unwrap_or(path);
-            format!("https://github.com/{}", path)
-        } else if url.starts_with("https://github.com/") {
-            let path = url.strip_prefix("https://github.com/")?;
-            let path = path.strip_suffix(".git").unwrap_or(path);
-            format!("https://github.com/{}", path)
-        } else {
-            return None;
-        };
+    fn commit_message(&self, commit: &CommitHash) -> Result<String, Self::Error> {
+        let commit_obj = self
+            .repo
+            .revparse_single(commit.as_str())?
+            .peel_to_commit()?;
+        Ok(commit_obj.message().unwrap_or("").to_string())
+    }
 
-        Some(format!("{}/commit/{}", github_base, commit.as_str()))
+    fn file_at(&self, commit: &CommitHash, path: &Path) -> Result<Vec<u8>, Self::Error> {
+        let commit_obj = self
+            .repo
+            .revparse_single(commit.as_str())?
+            .peel_to_commit()?;
+        let blob = commit_obj
+            .tree()?
+            .get_path(path)?
+            .to_object(&self.repo)?
+            .peel_to_blob()?;
+        Ok(blob.content().to_vec())
+    }
+
+    fn head(&self) -> Option<CommitHash> {
+        let commit = self.repo.head().ok()?.peel_to_commit().ok()?;
+        Some(CommitHash::new(commit.id().to_string()))
+    }
+
+    fn workdir(&self) -> Option<&Path> {
+        self.repo.workdir()
+    }
+
+    fn remotes(&self) -> Vec<(String, String)> {
+        let Ok(names) = self.repo.remotes() else {
+            return vec![];
+        };
+        names
+            .iter()
+            .flatten()
+            .filter_map(|name| {
+                let remote = self.repo.find_remote(name).ok()?;
+                Some((name.to_string(), remote.url()?.to_string()))
+            })
+            .collect()
     }
 }
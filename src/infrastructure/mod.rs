@@ -1,5 +1,13 @@
+pub mod clipboard;
+pub mod editor;
 pub mod formatter;
 pub mod git;
+pub mod session;
+pub mod shell;
 
+pub use clipboard::SystemClipboard;
+pub use editor::Editor;
 pub use formatter::DeltaFormatter;
 pub use git::Git2Gateway;
+pub use session::FileSessionStore;
+pub use shell::BackgroundJobs;
@@ -0,0 +1,194 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::application::port::SessionStore;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{Mark, Session};
+
+#[derive(Debug, Error)]
+pub enum SessionError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Invalid session file: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    #[error("Failed to write session: {0}")]
+    Serialize(#[from] toml::ser::Error),
+}
+
+/// Contents of `marks.toml`
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct MarksFile {
+    #[serde(default)]
+    marks: Vec<Mark>,
+}
+
+/// Sessions stored as TOML files, one directory per repository, next to its `marks.toml`
+pub struct FileSessionStore {
+    dir: PathBuf,
+}
+
+impl FileSessionStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Store under the user's data directory, keyed by the repository's working directory
+    pub fn for_repository(repo_root: &Path) -> Self {
+        let dir = dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("blake")
+            .join("sessions")
+            .join(path_key(repo_root));
+        Self::new(dir)
+    }
+
+    fn marks_path(&self) -> PathBuf {
+        self.dir.join("marks.toml")
+    }
+
+    fn session_path(&self, file: &Path) -> PathBuf {
+        self.dir.join(format!("{}.toml", path_key(file)))
+    }
+}
+
+impl SessionStore for FileSessionStore {
+    type Error = SessionError;
+
+    fn load(&self, file: &Path) -> Result<Option<Session>, Self::Error> {
+        match fs::read_to_string(self.session_path(file)) {
+            Ok(content) => Ok(Some(toml::from_str(&content)?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save(&self, session: &Session) -> Result<(), Self::Error> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.session_path(&session.file), toml::to_string(session)?)?;
+        Ok(())
+    }
+
+    fn list(&self) -> Result<Vec<Session>, Self::Error> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut sessions: Vec<Session> = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|e| e == "toml") && path != self.marks_path() {
+                // Skip files that fail to parse, e.g. from an older format
+                if let Ok(session) = toml::from_str(&fs::read_to_string(&path)?) {
+                    sessions.push(session);
+                }
+            }
+        }
+        sessions.sort_by_key(|s| std::cmp::Reverse(s.saved_at));
+        Ok(sessions)
+    }
+
+    fn delete(&self, file: &Path) -> Result<(), Self::Error> {
+        match fs::remove_file(self.session_path(file)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn load_marks(&self) -> Result<Vec<Mark>, Self::Error> {
+        match fs::read_to_string(self.marks_path()) {
+            Ok(content) => Ok(toml::from_str::<MarksFile>(&content)?.marks),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save_marks(&self, marks: &[Mark]) -> Result<(), Self::Error> {
+        fs::create_dir_all(&self.dir)?;
+        let file = MarksFile {
+            marks: marks.to_vec(),
+        };
+        fs::write(self.marks_path(), toml::to_string(&file)?)?;
+        Ok(())
+    }
+}
+
+/// Stable file name for a path (64-bit FNV-1a), so keys survive toolchain upgrades
+fn path_key(path: &Path) -> String {
+    let hash = path
+        .to_string_lossy()
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::session::SessionFrame;
+
+    fn session(file: &str, saved_at: i64) -> Session {
+        Session {
+            file: file.into(),
+            saved_at,
+            frames: vec![SessionFrame {
+                revision: "abc1234".to_string(),
+                path: file.into(),
+                selected_line: 12,
+                scroll_offset: 4,
+                drilled_through: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn saves_loads_and_lists() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileSessionStore::new(dir.path().join("repo"));
+        assert_eq!(store.load(Path::new("a.rs")).unwrap(), None);
+        assert!(store.list().unwrap().is_empty());
+
+        store.save(&session("a.rs", 1)).unwrap();
+        store.save(&session("b.rs", 2)).unwrap();
+        store.save(&session("a.rs", 3)).unwrap();
+
+        assert_eq!(
+            store.load(Path::new("a.rs")).unwrap(),
+            Some(session("a.rs", 3))
+        );
+        let files: Vec<PathBuf> = store.list().unwrap().into_iter().map(|s| s.file).collect();
+        assert_eq!(files, vec![PathBuf::from("a.rs"), PathBuf::from("b.rs")]);
+
+        store.delete(Path::new("a.rs")).unwrap();
+        store.delete(Path::new("a.rs")).unwrap();
+        assert_eq!(store.list().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn marks_round_trip_apart_from_sessions() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileSessionStore::new(dir.path().to_path_buf());
+        assert!(store.load_marks().unwrap().is_empty());
+
+        let marks = vec![Mark {
+            name: 'a',
+            path: "src/a.rs".into(),
+            revision: "abc1234".to_string(),
+            line: 120,
+        }];
+        store.save_marks(&marks).unwrap();
+        store.save(&session("a.rs", 1)).unwrap();
+
+        assert_eq!(store.load_marks().unwrap(), marks);
+        assert_eq!(store.list().unwrap().len(), 1);
+    }
+}
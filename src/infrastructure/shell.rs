@@ -0,0 +1,116 @@
+use std::io;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use crate::config::CommandOutput;
+
+/// A command line run by `sh -c` in `dir`
+pub fn shell(line: &str, dir: Option<&Path>) -> Command {
+    let mut command = Command::new("sh");
+    command.arg("-c").arg(line);
+    if let Some(dir) = dir {
+        command.current_dir(dir);
+    }
+    command
+}
+
+/// Stdout followed by stderr
+pub fn output_text(output: &Output) -> String {
+    let mut text = String::from_utf8_lossy(&output.stdout).to_string();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    text
+}
+
+/// One-line report of how a command ended
+pub fn summary(name: &str, result: &io::Result<Output>) -> String {
+    match result {
+        Ok(output) if output.status.success() => format!("{} finished", name),
+        Ok(output) => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            match stderr.lines().find(|l| !l.trim().is_empty()) {
+                Some(line) => format!("{} failed ({}): {}", name, output.status, line.trim()),
+                None => format!("{} failed ({})", name, output.status),
+            }
+        }
+        Err(e) => format!("{} could not start: {}", name, e),
+    }
+}
+
+/// A command that ran on its own thread, with where its output goes
+pub struct FinishedJob {
+    pub name: String,
+    pub output: CommandOutput,
+    pub result: io::Result<Output>,
+}
+
+/// Commands running on their own threads, collected as they finish
+pub struct BackgroundJobs {
+    sender: Sender<FinishedJob>,
+    receiver: Receiver<FinishedJob>,
+}
+
+impl BackgroundJobs {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self { sender, receiver }
+    }
+
+    pub fn spawn(&self, name: &str, output: CommandOutput, mut command: Command) {
+        let sender = self.sender.clone();
+        let name = name.to_string();
+        thread::spawn(move || {
+            let result = command.stdin(Stdio::null()).output();
+            let _ = sender.send(FinishedJob {
+                name,
+                output,
+                result,
+            });
+        });
+    }
+
+    /// Jobs that finished since the last call
+    pub fn finished(&self) -> Vec<FinishedJob> {
+        self.receiver.try_iter().collect()
+    }
+}
+
+impl Default for BackgroundJobs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn reports_background_results() {
+        let jobs = BackgroundJobs::new();
+        jobs.spawn("ok", CommandOutput::Pager, shell("printf done", None));
+        jobs.spawn(
+            "fail",
+            CommandOutput::Background,
+            shell("echo oops >&2; exit 3", None),
+        );
+
+        let mut results = Vec::new();
+        while results.len() < 2 {
+            results.extend(jobs.finished());
+            thread::sleep(Duration::from_millis(10));
+        }
+        results.sort_by(|a, b| a.name.cmp(&b.name));
+
+        assert_eq!(
+            summary(&results[0].name, &results[0].result),
+            "fail failed (exit status: 3): oops"
+        );
+        assert_eq!(results[0].output, CommandOutput::Background);
+        assert_eq!(summary(&results[1].name, &results[1].result), "ok finished");
+        assert_eq!(results[1].output, CommandOutput::Pager);
+        assert_eq!(output_text(results[1].result.as_ref().unwrap()), "done");
+    }
+}
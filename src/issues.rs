@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+use std::ops::Range;
+
+use regex::Regex;
+
+use crate::config::IssueTracker;
+use crate::domain::CommitHash;
+
+/// An issue reference found in a commit message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssueRef {
+    pub id: String,
+    pub url: String,
+    /// Byte range of the reference in the searched text
+    pub range: Range<usize>,
+}
+
+/// Finds issue references using the configured trackers
+#[derive(Debug, Clone, Default)]
+pub struct IssueLinker {
+    trackers: Vec<(Regex, String)>,
+}
+
+impl IssueLinker {
+    pub fn new(trackers: &[IssueTracker]) -> Result<Self, regex::Error> {
+        let trackers = trackers
+            .iter()
+            .map(|tracker| Ok((Regex::new(&tracker.pattern)?, tracker.url.clone())))
+            .collect::<Result<_, regex::Error>>()?;
+        Ok(Self { trackers })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.trackers.is_empty()
+    }
+
+    /// Every reference in `text`, in order; earlier trackers win where matches overlap
+    pub fn find(&self, text: &str) -> Vec<IssueRef> {
+        let mut refs: Vec<IssueRef> = Vec::new();
+        for (regex, template) in &self.trackers {
+            for captures in regex.captures_iter(text) {
+                let whole = captures.get(0).expect("group 0 always matches");
+                if refs
+                    .iter()
+                    .any(|r| r.range.start < whole.end() && whole.start() < r.range.end)
+                {
+                    continue;
+                }
+                let mut url = template.replace("{id}", whole.as_str());
+                for (i, group) in captures.iter().enumerate() {
+                    url = url.replace(&format!("{{{}}}", i), group.map_or("", |g| g.as_str()));
+                }
+                refs.push(IssueRef {
+                    id: whole.as_str().to_string(),
+                    url,
+                    range: whole.range(),
+                });
+            }
+        }
+        refs.sort_by_key(|r| r.range.start);
+        refs
+    }
+
+    /// Distinct references in `text`, in order of first mention
+    pub fn distinct(&self, text: &str) -> Vec<IssueRef> {
+        let mut refs = self.find(text);
+        let mut seen = HashSet::new();
+        refs.retain(|r| seen.insert(r.id.clone()));
+        refs
+    }
+}
+
+/// Commits whose messages mention an issue, checked as they appear in frames
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssueHighlight {
+    pub id: String,
+    matching: HashSet<CommitHash>,
+    checked: HashSet<CommitHash>,
+}
+
+impl IssueHighlight {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            matching: HashSet::new(),
+            checked: HashSet::new(),
+        }
+    }
+
+    pub fn contains(&self, commit: &CommitHash) -> bool {
+        self.matching.contains(commit)
+    }
+
+    pub fn is_checked(&self, commit: &CommitHash) -> bool {
+        self.checked.contains(commit)
+    }
+
+    pub fn record(&mut self, commit: &CommitHash, message: &str) {
+        if mentions(message, &self.id) {
+            self.matching.insert(commit.clone());
+        }
+        self.checked.insert(commit.clone());
+    }
+}
+
+/// Whether `text` mentions `id` as a whole word, e.g. `PAY-12` but not `PAY-123`
+pub fn mentions(text: &str, id: &str) -> bool {
+    if id.is_empty() {
+        return false;
+    }
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(id).any(|(i, _)| {
+        let before = text[..i].chars().next_back();
+        let after = text[i + id.len()..].chars().next();
+        !before.is_some_and(is_word) && !after.is_some_and(is_word)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linker() -> IssueLinker {
+        IssueLinker::new(&[
+            IssueTracker {
+                pattern: r"\b(PAY|OPS)-(\d+)\b".to_string(),
+                url: "https://jira.example.com/browse/{id}".to_string(),
+            },
+            IssueTracker {
+                pattern: r"\b([A-Z]+)-(\d+)\b".to_string(),
+                url: "https://linear.app/acme/issue/{1}-{2}".to_string(),
+            },
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn finds_references_with_their_links() {
+        let refs = linker().find("Fix ENG-7 rounding (PAY-1234)\n\nSee PAY-1234.");
+        let found: Vec<(&str, &str)> = refs
+            .iter()
+            .map(|r| (r.id.as_str(), r.url.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("ENG-7", "https://linear.app/acme/issue/ENG-7"),
+                ("PAY-1234", "https://jira.example.com/browse/PAY-1234"),
+                ("PAY-1234", "https://jira.example.com/browse/PAY-1234"),
+            ]
+        );
+        assert_eq!(refs[1].range, 20..28);
+        assert_eq!(linker().distinct("PAY-1 PAY-1 OPS-2").len(), 2);
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        let tracker = IssueTracker {
+            pattern: "(".to_string(),
+            url: String::new(),
+        };
+        assert!(IssueLinker::new(&[tracker]).is_err());
+    }
+
+    #[test]
+    fn mentions_whole_words_only() {
+        assert!(mentions("Fix PAY-12.", "PAY-12"));
+        assert!(mentions("PAY-12", "PAY-12"));
+        assert!(!mentions("Fix PAY-123", "PAY-12"));
+        assert!(!mentions("XPAY-12", "PAY-12"));
+        assert!(!mentions("anything", ""));
+    }
+}
@@ -1,11 +1,17 @@
 mod application;
+mod cli;
+mod commands;
 mod config;
 mod domain;
+mod export;
+mod forge;
 mod infrastructure;
+mod issues;
+mod print;
 mod ui;
 
 use std::io::stdout;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use anyhow::Result;
@@ -17,24 +23,34 @@ use crossterm::terminal::{
 use ratatui::prelude::*;
 use ratatui::widgets::StatefulWidget;
 
-use crate::config::ConfigLoader;
-use crate::infrastructure::{DeltaFormatter, Git2Gateway};
+use crate::cli::Cli;
+use crate::config::{Coloring, CommandOutput, ConfigLoader, KeyBinding};
+use crate::domain::date;
+use crate::domain::{BlameFrame, GitGateway};
+use crate::infrastructure::shell;
+use crate::infrastructure::{
+    BackgroundJobs, DeltaFormatter, Editor, FileSessionStore, Git2Gateway, SystemClipboard,
+};
+use crate::print::PrintOptions;
 use crate::ui::app::{App, LayoutState};
-use crate::ui::event::{EventHandler, key_to_action};
+use crate::ui::event::EventHandler;
+use crate::ui::heatmap::Heatmap;
 use crate::ui::layout;
 use crate::ui::mode::Mode;
+use crate::ui::prompt::PromptKind;
 use crate::ui::widget::{
-    BlameView, BlameViewState, DiffView, DiffViewState, HelpView, HelpViewState, StatusBar,
+    AuthorLegend, BlameView, BlameViewState, CommandPalette, DiffView, DiffViewState, HelpView,
+    HelpViewState, IssuesView, MarksView, SessionsView, StatsView, StatsViewState, StatusBar,
 };
 
 fn main() -> Result<()> {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Usage: blake <file>");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let cli = Cli::parse(&args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
         std::process::exit(1);
-    }
+    });
 
-    let file_path = PathBuf::from(&args[1]);
+    let file_path = cli.file_path.clone();
     if !file_path.exists() {
         eprintln!("File not found: {}", file_path.display());
         std::process::exit(1);
@@ -46,19 +62,62 @@ fn main() -> Result<()> {
         std::process::exit(1);
     });
 
-    run(file_path)
+    run(cli, file_path)
 }
 
-fn run(file_path: PathBuf) -> Result<()> {
+fn run(cli: Cli, file_path: PathBuf) -> Result<()> {
     // Load config
-    let config = ConfigLoader::load()?;
+    let mut config = ConfigLoader::load()?;
 
     // Create dependencies
-    let git = Git2Gateway::open_current()?;
+    let mut git = Git2Gateway::open_current()?.with_mailmap(config.general.mailmap)?;
+    if let Some(boundary) = &cli.boundary {
+        git = git.with_history_boundary(boundary, &cli.revision)?;
+    }
     let formatter = DeltaFormatter::new();
 
+    // Fall back to git's blame.date when blake's config doesn't set a format
+    if config.blame.date.is_none()
+        && let Some(format) = git.blame_date_format()
+    {
+        config.blame.date = format.parse().ok();
+    }
+
+    // Print mode doesn't need delta or a terminal
+    if let Some(format) = cli.print {
+        let frame = git.blame(&file_path, &cli.revision)?;
+        let frame = print::drill(&git, frame, &cli.drill)?;
+        let options = PrintOptions {
+            format,
+            line_range: cli.line_range,
+            date_format: config.blame.date_format(),
+            show_raw_identity: config.general.show_raw_identity,
+        };
+        print!("{}", print::render(&git, &frame, &options)?);
+        return Ok(());
+    }
+
+    // Sessions are stored per repository
+    let repo_root = git.workdir().map(Path::to_path_buf).unwrap_or_default();
+    let sessions = FileSessionStore::for_repository(&repo_root);
+
     // Create app
-    let mut app = App::new(git, formatter, config, file_path)?;
+    let clipboard = SystemClipboard::new(config.clipboard.clone());
+    let mut app = App::new(
+        git,
+        formatter,
+        sessions,
+        clipboard,
+        config,
+        file_path,
+        &cli.revision,
+    )?;
+    if cli.resume {
+        app.resume()?;
+    }
+
+    let editor = Editor::new(&app.config.editor);
+    let jobs = BackgroundJobs::new();
 
     // Setup terminal
     enable_raw_mode()?;
@@ -75,12 +134,20 @@ fn run(file_path: PathBuf) -> Result<()> {
         scroll_offset: 0,
         selected_line: 0,
     };
+    let mut stats_state = StatsViewState { scroll_offset: 0 };
 
     // Event handler
     let event_handler = EventHandler::new(Duration::from_millis(100));
 
     // Main loop
     loop {
+        // Each frame keeps its own scroll position
+        if let Some(frame) = app.blame_stack.current() {
+            blame_state.scroll_offset = frame.scroll_offset;
+        }
+        app.refresh_issue_highlight();
+        app.refresh_authors();
+
         // Render
         terminal.draw(|frame| {
             render(
@@ -89,13 +156,65 @@ fn run(file_path: PathBuf) -> Result<()> {
                 &mut blame_state,
                 &mut diff_state,
                 &mut help_state,
+                &mut stats_state,
             );
         })?;
 
+        if let Some(frame) = app.blame_stack.current_mut() {
+            frame.scroll_offset = blame_state.scroll_offset;
+        }
+
         // Handle events
         if let Some(Event::Key(key)) = event_handler.poll()? {
-            let action = key_to_action(key, &app.mode, &app.config.keymap);
-            app.dispatch(action)?;
+            app.handle_key(KeyBinding::from(key))?;
+        }
+        app.expire_keys()?;
+
+        for job in jobs.finished() {
+            match (job.output, &job.result) {
+                (CommandOutput::Pager, Ok(output)) => {
+                    app.status_message = None;
+                    app.show_output(&job.name, &shell::output_text(output));
+                }
+                _ => app.status_message = Some(shell::summary(&job.name, &job.result)),
+            }
+        }
+
+        if let Some(request) = app.command_request.take() {
+            let mut command = shell::shell(&request.line, request.dir.as_deref());
+            match request.output {
+                CommandOutput::Background | CommandOutput::Pager => {
+                    jobs.spawn(&request.name, request.output, command);
+                    app.status_message = Some(format!("Running {}", request.name));
+                }
+                CommandOutput::Terminal => {
+                    let status = suspended(&mut terminal, || {
+                        let status = command.status();
+                        // Keep the output on screen until the user is done reading it
+                        println!("\n[Press Enter to return to blake]");
+                        let _ = std::io::stdin().read_line(&mut String::new());
+                        status
+                    })?;
+                    app.status_message = match status {
+                        Ok(status) if status.success() => None,
+                        Ok(status) => Some(format!("{} exited with {}", request.name, status)),
+                        Err(e) => Some(format!("{} could not start: {}", request.name, e)),
+                    };
+                }
+            }
+        }
+
+        if let Some(request) = app.edit_request.take() {
+            let mut command = editor.command(&request.path, request.line);
+            let status = suspended(&mut terminal, || command.status())?;
+            app.status_message = match status {
+                Ok(status) if status.success() => None,
+                Ok(status) => Some(format!("Editor exited with {}", status)),
+                Err(e) => Some(format!("Failed to start editor: {}", e)),
+            };
+            if request.working_file && app.status_message.is_none() {
+                app.refresh_after_edit();
+            }
         }
 
         if app.should_quit {
@@ -107,15 +226,35 @@ fn run(file_path: PathBuf) -> Result<()> {
     disable_raw_mode()?;
     stdout().execute(LeaveAlternateScreen)?;
 
+    if let Err(e) = app.save_session() {
+        eprintln!("Failed to save session: {}", e);
+    }
+
     Ok(())
 }
 
+/// Hand the terminal to a child process, restoring the TUI afterwards
+fn suspended<B: Backend, T>(terminal: &mut Terminal<B>, run: impl FnOnce() -> T) -> Result<T>
+where
+    B::Error: Send + Sync + 'static,
+{
+    disable_raw_mode()?;
+    stdout().execute(LeaveAlternateScreen)?;
+    let result = run();
+    enable_raw_mode()?;
+    stdout().execute(EnterAlternateScreen)?;
+    // The child drew over the screen, so the next frame must repaint everything
+    terminal.clear()?;
+    Ok(result)
+}
+
 fn render(
-    app: &App<Git2Gateway, DeltaFormatter>,
+    app: &App<Git2Gateway, DeltaFormatter, FileSessionStore, SystemClipboard>,
     frame: &mut Frame,
     blame_state: &mut BlameViewState,
     diff_state: &mut DiffViewState,
     help_state: &mut HelpViewState,
+    stats_state: &mut StatsViewState,
 ) {
     let area = frame.area();
 
@@ -125,19 +264,8 @@ fn render(
 
             // Blame view
             if let Some(blame_frame) = app.blame_stack.current() {
-                let blame_view = BlameView::new(blame_frame);
-                blame_view.render(layout.main, frame.buffer_mut(), blame_state);
-
-                // Status bar
-                let status_bar = StatusBar::new(
-                    app.mode,
-                    &blame_frame.file_path.to_string_lossy(),
-                    blame_frame.selected_line,
-                    blame_frame.entries.len(),
-                    app.blame_stack.hash_chain(),
-                )
-                .with_message(app.status_message.as_deref());
-                frame.render_widget(status_bar, layout.status_bar);
+                blame_view(app, blame_frame).render(layout.main, frame.buffer_mut(), blame_state);
+                frame.render_widget(status_bar(app, blame_frame), layout.status_bar);
             }
         }
         LayoutState::Split { ratio } => {
@@ -145,35 +273,131 @@ fn render(
 
             // Blame view
             if let Some(blame_frame) = app.blame_stack.current() {
-                let blame_view = BlameView::new(blame_frame);
-                blame_view.render(split.blame, frame.buffer_mut(), blame_state);
-
-                // Status bar
-                let status_bar = StatusBar::new(
-                    app.mode,
-                    &blame_frame.file_path.to_string_lossy(),
-                    blame_frame.selected_line,
-                    blame_frame.entries.len(),
-                    app.blame_stack.hash_chain(),
-                )
-                .with_message(app.status_message.as_deref());
-                frame.render_widget(status_bar, split.status_bar);
+                blame_view(app, blame_frame).render(split.blame, frame.buffer_mut(), blame_state);
+                frame.render_widget(status_bar(app, blame_frame), split.status_bar);
             }
 
             // Diff view
             if let Some(lines) = &app.diff_lines {
                 diff_state.selected_line = app.diff_selected_line;
-                let diff_view = DiffView::new(lines, app.diff_commit_info.as_ref());
+                let diff_view = DiffView::new(lines, app.diff_commit_info.as_ref(), &app.theme)
+                    .with_header(&app.config.diff.header)
+                    .with_pull_request(app.diff_pull_request.as_ref())
+                    .with_issues(&app.issue_linker)
+                    .with_title(app.pane_title.as_deref().unwrap_or("Diff"))
+                    .with_wrap(app.diff_wrap)
+                    .with_date_format(&app.config.diff.date)
+                    .with_raw_identity(app.config.general.show_raw_identity);
                 diff_view.render(split.diff, frame.buffer_mut(), diff_state);
             }
         }
     }
 
+    // Author legend overlay
+    if app.show_author_legend && app.mode != Mode::Help {
+        let legend = AuthorLegend::new(&app.authors, &app.author_slots, &app.theme)
+            .with_raw_identity(app.config.general.show_raw_identity);
+        frame.render_widget(legend, area);
+    }
+
+    // Ownership overlay
+    if app.mode == Mode::Stats
+        && let (Some(stats), Some(blame_frame)) = (&app.stats, app.blame_stack.current())
+    {
+        let date_format = app.config.blame.date_format();
+        let stats_view = StatsView::new(
+            stats,
+            blame_frame,
+            &app.theme,
+            date::now(),
+            app.stats_selected,
+        )
+        .with_highlighted(app.owner_highlight.as_ref())
+        .with_date_format(&date_format)
+        .with_author_slots(&app.author_slots)
+        .with_raw_identity(app.config.general.show_raw_identity);
+        stats_view.render(area, frame.buffer_mut(), stats_state);
+    }
+
+    // Sessions overlay
+    if app.mode == Mode::Sessions {
+        let sessions_view = SessionsView::new(
+            &app.session_list,
+            &app.theme,
+            date::now(),
+            app.sessions_selected,
+        );
+        frame.render_widget(sessions_view, area);
+    }
+
+    // Issues overlay
+    if app.mode == Mode::Issues {
+        let issues_view = IssuesView::new(&app.issues, &app.theme, app.issues_selected);
+        frame.render_widget(issues_view, area);
+    }
+
+    // Marks overlay
+    if app.mode == Mode::Marks {
+        let marks_view = MarksView::new(&app.marks, &app.theme, app.marks_selected);
+        frame.render_widget(marks_view, area);
+    }
+
     // Help overlay
     if matches!(app.mode, Mode::Help) {
         help_state.scroll_offset = app.help_scroll;
         help_state.selected_line = app.help_selected_line;
-        let help_view = HelpView::new(&app.config.keymap);
+        let help_view =
+            HelpView::new(&app.config.keymap, &app.theme).with_commands(&app.config.commands);
         help_view.render(area, frame.buffer_mut(), help_state);
     }
+
+    // Command line completions
+    if app
+        .prompt
+        .as_ref()
+        .is_some_and(|p| p.kind == PromptKind::Command)
+    {
+        frame.render_widget(CommandPalette::new(&app.completion, &app.theme), area);
+    }
+}
+
+fn blame_view<'a>(
+    app: &'a App<Git2Gateway, DeltaFormatter, FileSessionStore, SystemClipboard>,
+    blame_frame: &'a BlameFrame,
+) -> BlameView<'a> {
+    BlameView::new(blame_frame, &app.config.blame, &app.theme)
+        .with_trail(&app.blame_stack)
+        .with_subject(app.show_subject)
+        .with_coloring(app.coloring)
+        .with_block_style(app.block_style)
+        .with_owner(app.owner_highlight.as_ref())
+        .with_issue(app.issue_highlight.as_ref())
+        .with_author_slots(&app.author_slots)
+        .with_highlight(app.highlighter.as_ref().and_then(|h| h.cached(blame_frame)))
+        .with_raw_identity(app.config.general.show_raw_identity)
+}
+
+fn status_bar<'a>(
+    app: &'a App<Git2Gateway, DeltaFormatter, FileSessionStore, SystemClipboard>,
+    blame_frame: &BlameFrame,
+) -> StatusBar<'a> {
+    let legend = if app.coloring == Coloring::Heatmap && !app.theme.no_color {
+        Heatmap::new(&app.config.blame.heatmap, &blame_frame.entries, date::now())
+            .legend(app.theme.status_bar)
+    } else {
+        Vec::new()
+    };
+
+    StatusBar::new(
+        &app.theme,
+        app.mode,
+        &blame_frame.file_path.to_string_lossy(),
+        blame_frame.selected_line,
+        blame_frame.entries.len(),
+        app.blame_stack.hash_chain(),
+    )
+    .with_message(app.status_message.as_deref())
+    .with_prompt(app.prompt.as_ref())
+    .with_pending(app.pending_keys.hint())
+    .with_legend(legend)
 }
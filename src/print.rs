@@ -0,0 +1,501 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+
+use crate::domain::date::{self, DateFormat};
+use crate::domain::{BlameEntry, BlameFrame, GitGateway};
+
+/// Non-interactive output formats for `--print` and `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// `git blame`-style annotated lines
+    Plain,
+    Json,
+    /// `git blame --porcelain`
+    Porcelain,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Self::Plain),
+            "json" => Ok(Self::Json),
+            "porcelain" => Ok(Self::Porcelain),
+            _ => Err(format!(
+                "Unknown format: {} (expected plain, json or porcelain)",
+                s
+            )),
+        }
+    }
+}
+
+/// Options shared by all output formats
+pub struct PrintOptions {
+    pub format: OutputFormat,
+    /// 1-based inclusive line range
+    pub line_range: Option<(usize, usize)>,
+    pub date_format: DateFormat,
+    pub show_raw_identity: bool,
+}
+
+/// Drill down through `lines` in turn, as if pressing Enter on each one in the TUI
+pub fn drill<G: GitGateway>(git: &G, mut frame: BlameFrame, lines: &[usize]) -> Result<BlameFrame> {
+    for &line in lines {
+        let entry = frame
+            .entries
+            .iter()
+            .find(|e| e.line_number == line)
+            .ok_or_else(|| {
+                anyhow!(
+                    "--drill {}: {} has only {} lines at {}",
+                    line,
+                    frame.file_path.display(),
+                    frame.entries.len(),
+                    frame.commit_hash.short()
+                )
+            })?;
+        if entry.boundary {
+            return Err(anyhow!(
+                "--drill {}: line is older than the history boundary ^{}",
+                line,
+                entry.commit_hash.short()
+            ));
+        }
+
+        let commit_info = git.commit_info(&entry.commit_hash)?;
+        let parent = commit_info.parent().ok_or_else(|| {
+            anyhow!(
+                "--drill {}: line was added in the root commit {}",
+                line,
+                entry.commit_hash.short()
+            )
+        })?;
+        let drilled_through = entry.commit_hash.clone();
+        frame = git.blame(&frame.file_path, parent).map_err(|_| {
+            anyhow!(
+                "--drill {}: {} does not exist before {}",
+                line,
+                frame.file_path.display(),
+                drilled_through.short()
+            )
+        })?;
+        frame.drilled_through = Some(drilled_through);
+    }
+    Ok(frame)
+}
+
+/// Render a frame in the requested format, ending with a newline
+pub fn render<G: GitGateway>(
+    git: &G,
+    frame: &BlameFrame,
+    options: &PrintOptions,
+) -> Result<String> {
+    let entries = select_lines(frame, options.line_range)?;
+    match options.format {
+        OutputFormat::Plain => Ok(plain(frame, entries, options, date::now())),
+        OutputFormat::Json => json(frame, entries),
+        OutputFormat::Porcelain => porcelain(git, frame, entries),
+    }
+}
+
+/// Entries within the line range, which must start inside the file
+fn select_lines(frame: &BlameFrame, line_range: Option<(usize, usize)>) -> Result<&[BlameEntry]> {
+    let Some((start, end)) = line_range else {
+        return Ok(&frame.entries);
+    };
+    let total = frame.entries.len();
+    if start > total {
+        return Err(anyhow!(
+            "-L {}: {} has only {} lines",
+            start,
+            frame.file_path.display(),
+            total
+        ));
+    }
+    Ok(&frame.entries[start - 1..end.min(total)])
+}
+
+fn plain(frame: &BlameFrame, entries: &[BlameEntry], options: &PrintOptions, now: i64) -> String {
+    let author = |e: &BlameEntry| e.author_label(options.show_raw_identity);
+    let author_width = entries
+        .iter()
+        .map(|e| author(e).chars().count())
+        .max()
+        .unwrap_or(0);
+    let line_width = entries
+        .last()
+        .map_or(1, |e| e.line_number.to_string().len());
+    // Like git, only show paths when some lines came from another file
+    let path_width = entries.iter().any(|e| e.original_path.is_some()).then(|| {
+        entries
+            .iter()
+            .map(|e| original_path(frame, e).to_string_lossy().chars().count())
+            .max()
+            .unwrap_or(0)
+    });
+
+    let mut out = String::new();
+    for entry in entries {
+        let short = entry.commit_hash.short();
+        let hash = if entry.boundary {
+            format!("^{}", &short[..short.len().saturating_sub(1)])
+        } else {
+            short.to_string()
+        };
+        let path = match path_width {
+            Some(width) => format!(" {:width$}", original_path(frame, entry).to_string_lossy()),
+            None => String::new(),
+        };
+        let _ = writeln!(
+            out,
+            "{}{} ({:author_width$} {} {:>line_width$}) {}",
+            hash,
+            path,
+            author(entry),
+            options
+                .date_format
+                .format(entry.timestamp, entry.offset_minutes, now),
+            entry.line_number,
+            entry.content
+        );
+    }
+    out
+}
+
+#[derive(Serialize)]
+struct JsonBlame<'a> {
+    path: &'a Path,
+    revision: &'a str,
+    lines: Vec<JsonLine<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonLine<'a> {
+    line: usize,
+    commit: &'a str,
+    author: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    raw_author: Option<&'a str>,
+    author_email: &'a str,
+    author_time: i64,
+    author_tz: String,
+    summary: &'a str,
+    original_line: usize,
+    original_path: &'a Path,
+    boundary: bool,
+    content: &'a str,
+}
+
+fn json(frame: &BlameFrame, entries: &[BlameEntry]) -> Result<String> {
+    let blame = JsonBlame {
+        path: &frame.file_path,
+        revision: frame.commit_hash.as_str(),
+        lines: entries
+            .iter()
+            .map(|e| JsonLine {
+                line: e.line_number,
+                commit: e.commit_hash.as_str(),
+                author: &e.author,
+                raw_author: e.raw_author.as_deref(),
+                author_email: &e.author_email,
+                author_time: e.timestamp,
+                author_tz: date::format_offset(e.offset_minutes),
+                summary: &e.summary,
+                original_line: e.original_line_number,
+                original_path: original_path(frame, e),
+                boundary: e.boundary,
+                content: &e.content,
+            })
+            .collect(),
+    };
+    Ok(serde_json::to_string_pretty(&blame)? + "\n")
+}
+
+/// `git blame --porcelain`: commit headers are printed once, on a commit's first line
+fn porcelain<G: GitGateway>(git: &G, frame: &BlameFrame, entries: &[BlameEntry]) -> Result<String> {
+    let first_index = entries
+        .first()
+        .map_or(0, |e| e.line_number.saturating_sub(1));
+    let range_end = first_index + entries.len();
+    let mut seen = HashSet::new();
+    let mut out = String::new();
+
+    for (offset, entry) in entries.iter().enumerate() {
+        let index = first_index + offset;
+        let mut header = format!(
+            "{} {} {}",
+            entry.commit_hash, entry.original_line_number, entry.line_number
+        );
+        // The first line of each hunk in range carries its line count
+        let hunk = frame.hunk_index(index).and_then(|h| frame.hunks.get(h));
+        if offset == 0 || hunk.is_none_or(|h| h.start == index) {
+            let count = hunk.map_or(1, |h| h.end.min(range_end) - index);
+            let _ = write!(header, " {}", count);
+        }
+        let _ = writeln!(out, "{}", header);
+
+        if seen.insert(&entry.commit_hash) {
+            let info = git.commit_info(&entry.commit_hash)?;
+            let committer = &info.committer;
+            let _ = writeln!(out, "author {}", entry.author);
+            let _ = writeln!(out, "author-mail <{}>", entry.author_email);
+            let _ = writeln!(out, "author-time {}", entry.timestamp);
+            let _ = writeln!(
+                out,
+                "author-tz {}",
+                date::format_offset(entry.offset_minutes)
+            );
+            let _ = writeln!(out, "committer {}", committer.name);
+            let _ = writeln!(out, "committer-mail <{}>", committer.email);
+            let _ = writeln!(out, "committer-time {}", committer.timestamp);
+            let _ = writeln!(
+                out,
+                "committer-tz {}",
+                date::format_offset(committer.offset_minutes)
+            );
+            let _ = writeln!(out, "summary {}", entry.summary);
+            // git also reports root commits as boundaries
+            if entry.boundary || info.parent().is_none() {
+                let _ = writeln!(out, "boundary");
+            } else if let Some(parent) = info.parent() {
+                let _ = writeln!(
+                    out,
+                    "previous {} {}",
+                    parent,
+                    original_path(frame, entry).display()
+                );
+            }
+            let _ = writeln!(out, "filename {}", original_path(frame, entry).display());
+        }
+        let _ = writeln!(out, "\t{}", entry.content);
+    }
+    Ok(out)
+}
+
+/// Path the line had in its commit
+fn original_path<'a>(frame: &'a BlameFrame, entry: &'a BlameEntry) -> &'a Path {
+    entry.original_path.as_deref().unwrap_or(&frame.file_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::date::DateStyle;
+    use crate::domain::{BlameStack, CommitHash, CommitInfo, Diff, Signature};
+    use crate::infrastructure::Git2Gateway;
+
+    struct FakeGit;
+
+    impl GitGateway for FakeGit {
+        type Error = std::io::Error;
+
+        fn blame(&self, _: &Path, commit: &CommitHash) -> Result<BlameFrame, Self::Error> {
+            Ok(frame(commit.as_str(), &[("parent", "Carol", "old")]))
+        }
+
+        fn diff(&self, _: &CommitHash) -> Result<Diff, Self::Error> {
+            unimplemented!()
+        }
+
+        fn commit_info(&self, commit: &CommitHash) -> Result<CommitInfo, Self::Error> {
+            let signature = Signature {
+                name: "Committer".to_string(),
+                email: "c@example.com".to_string(),
+                raw_name: None,
+                timestamp: 1_700_000_000,
+                offset_minutes: 60,
+            };
+            let parents = if commit.as_str() == "root" {
+                vec![]
+            } else {
+                vec![CommitHash::new(format!("{}^", commit))]
+            };
+            Ok(CommitInfo {
+                hash: commit.clone(),
+                parents,
+                author: signature.clone(),
+                committer: signature,
+                message: String::new(),
+                refs: vec![],
+                trailers: vec![],
+            })
+        }
+
+        fn commit_message(&self, _: &CommitHash) -> Result<String, Self::Error> {
+            Ok(String::new())
+        }
+
+        fn file_at(&self, _: &CommitHash, _: &Path) -> Result<Vec<u8>, Self::Error> {
+            unimplemented!()
+        }
+
+        fn head(&self) -> Option<CommitHash> {
+            None
+        }
+
+        fn workdir(&self) -> Option<&Path> {
+            None
+        }
+
+        fn remotes(&self) -> Vec<(String, String)> {
+            vec![]
+        }
+    }
+
+    fn frame(revision: &str, lines: &[(&str, &str, &str)]) -> BlameFrame {
+        let entries = lines
+            .iter()
+            .map(|&(hash, author, content)| {
+                BlameEntry::fixture(hash, author)
+                    .with_time(1_700_000_000, -300)
+                    .with_summary(&format!("Commit {}", hash))
+                    .with_content(content)
+            })
+            .collect();
+        BlameFrame::fixture(revision, entries).with_path("src/a.rs")
+    }
+
+    fn sample() -> BlameFrame {
+        frame(
+            "head",
+            &[
+                ("aaaaaaaaaa", "Alice", "fn main() {"),
+                ("bbbbbbbbbb", "Bob", "    run();"),
+                ("bbbbbbbbbb", "Bob", "    exit();"),
+                ("aaaaaaaaaa", "Alice", "}"),
+            ],
+        )
+    }
+
+    fn options(format: OutputFormat, line_range: Option<(usize, usize)>) -> PrintOptions {
+        PrintOptions {
+            format,
+            line_range,
+            date_format: DateFormat::new(DateStyle::Short),
+            show_raw_identity: false,
+        }
+    }
+
+    #[test]
+    fn plain_output_aligns_columns() {
+        let out = render(
+            &FakeGit,
+            &sample(),
+            &options(OutputFormat::Plain, Some((1, 2))),
+        )
+        .unwrap();
+        assert_eq!(
+            out,
+            "aaaaaaa (Alice 2023-11-14 1) fn main() {\n\
+             bbbbbbb (Bob   2023-11-14 2)     run();\n"
+        );
+    }
+
+    #[test]
+    fn json_output_lists_lines() {
+        let out = render(
+            &FakeGit,
+            &sample(),
+            &options(OutputFormat::Json, Some((4, 9))),
+        )
+        .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value["revision"], "head");
+        assert_eq!(value["lines"].as_array().unwrap().len(), 1);
+        assert_eq!(value["lines"][0]["line"], 4);
+        assert_eq!(value["lines"][0]["author_tz"], "-0500");
+        assert_eq!(value["lines"][0]["original_path"], "src/a.rs");
+    }
+
+    #[test]
+    fn porcelain_prints_headers_once() {
+        let out = render(
+            &FakeGit,
+            &sample(),
+            &options(OutputFormat::Porcelain, Some((2, 4))),
+        )
+        .unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "bbbbbbbbbb 2 2 2");
+        assert_eq!(lines[1], "author Bob");
+        assert!(lines.contains(&"previous bbbbbbbbbb^ src/a.rs"));
+        assert!(lines.contains(&"bbbbbbbbbb 3 3"));
+        assert!(lines.contains(&"aaaaaaaaaa 4 4 1"));
+        assert_eq!(out.matches("author-time").count(), 2);
+        assert_eq!(out.matches("\t").count(), 3);
+    }
+
+    #[test]
+    fn range_past_the_end_is_an_error() {
+        assert!(
+            render(
+                &FakeGit,
+                &sample(),
+                &options(OutputFormat::Plain, Some((5, 6)))
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn drill_follows_parents() {
+        let drilled = drill(&FakeGit, sample(), &[2, 1]).unwrap();
+        assert_eq!(drilled.commit_hash, CommitHash::new("parent^"));
+
+        let root = frame("head", &[("root", "Alice", "x")]);
+        assert!(drill(&FakeGit, root, &[1]).is_err());
+        assert!(drill(&FakeGit, sample(), &[9]).is_err());
+    }
+
+    /// Commit `content` as `file` on top of HEAD, returning the new commit
+    fn commit_file(repo: &git2::Repository, file: &str, content: &str) -> CommitHash {
+        std::fs::write(repo.workdir().unwrap().join(file), content).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new(file)).unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = git2::Signature::now("Alice", "alice@example.com").unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        let oid = repo
+            .commit(
+                Some("HEAD"),
+                &signature,
+                &signature,
+                "edit",
+                &tree,
+                &parents,
+            )
+            .unwrap();
+        CommitHash::new(oid.to_string())
+    }
+
+    #[test]
+    fn drilled_frames_number_the_commits_they_passed_through() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = git2::Repository::init(dir.path()).unwrap();
+        commit_file(&repo, "a.rs", "one\ntwo\n");
+        let second = commit_file(&repo, "a.rs", "one\nTWO\n");
+        let third = commit_file(&repo, "a.rs", "ONE\nTWO\n");
+
+        let git = Git2Gateway::open(dir.path()).unwrap();
+        let path = repo.workdir().unwrap().join("a.rs");
+        let mut stack = BlameStack::new();
+        stack.push(git.blame(&path, &third).unwrap());
+        for line in [1, 2] {
+            let current = stack.current().unwrap().clone();
+            stack.push(drill(&git, current, &[line]).unwrap());
+        }
+
+        // Line 1 was drilled through in frame 1 and line 2 in frame 2
+        assert_eq!(stack.trail_position(&third), Some(1));
+        assert_eq!(stack.trail_position(&second), Some(2));
+        let top = &stack.frames()[0];
+        assert_eq!(stack.trail_position(&top.entries[1].commit_hash), Some(2));
+    }
+}
@@ -12,6 +12,8 @@ pub enum BlameAction {
     CursorPageDown,
     CursorTop,
     CursorBottom,
+    NextHunk,
+    PreviousHunk,
 
     // Blame navigation
     DrillDown,
@@ -19,4 +21,48 @@ pub enum BlameAction {
 
     // Show diff
     ShowDiff,
+
+    // Overlays
+    ShowStats,
+    ShowSessions,
+    ShowMarks,
+
+    // Marks
+    /// Wait for a mark name, then mark the selected line
+    SetMark,
+    /// Wait for a mark name, then jump to it
+    JumpToMark,
+
+    // Editor
+    /// Open the selected line in `$VISUAL` or `$EDITOR`
+    OpenInEditor,
+
+    // Reports
+    ExportTrail,
+
+    // Issues
+    /// List the issue references of the selected line's commit
+    ShowIssues,
+    /// Ask for an issue and highlight lines whose commit mentions it
+    HighlightIssue,
+
+    // Clipboard
+    YankHash,
+    YankShortHash,
+    YankLine,
+    YankSubject,
+    YankPermalink,
+    YankLineLink,
+    YankHunkLink,
+
+    // Browser
+    OpenLineLink,
+    OpenHunkLink,
+    OpenPullRequest,
+
+    // Display
+    ToggleAuthorSubject,
+    CycleColoring,
+    ToggleAuthorLegend,
+    CycleBlockStyle,
 }
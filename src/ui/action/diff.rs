@@ -20,6 +20,17 @@ pub enum DiffAction {
     // Close diff view
     Close,
 
-    // Open in GitHub
-    OpenInGitHub,
+    // Open the commit page on the repository's forge
+    #[serde(alias = "OpenInGitHub")]
+    OpenInBrowser,
+    /// Open the pull request the commit was merged through
+    OpenPullRequest,
+    /// List the issue references in the commit message
+    ShowIssues,
+
+    // Clipboard
+    YankHash,
+    YankShortHash,
+    YankSubject,
+    YankPermalink,
 }
@@ -5,4 +5,6 @@ use serde::{Deserialize, Serialize};
 pub enum GlobalAction {
     Quit,
     ShowHelp,
+    /// Open the `:` command line
+    CommandLine,
 }
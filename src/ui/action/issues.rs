@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Actions for the issue references list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum IssuesAction {
+    CursorUp,
+    CursorDown,
+    /// Open the selected issue in the browser
+    Select,
+    /// Highlight blame lines whose commit mentions the selected issue
+    Highlight,
+    Close,
+}
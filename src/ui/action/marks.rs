@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Actions for the marks list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum MarksAction {
+    CursorUp,
+    CursorDown,
+    /// Jump to the selected mark
+    Select,
+    Delete,
+    Close,
+}
@@ -2,11 +2,21 @@ mod blame;
 mod diff;
 mod global;
 mod help;
+mod issues;
+mod marks;
+mod prompt;
+mod sessions;
+mod stats;
 
 pub use blame::BlameAction;
 pub use diff::DiffAction;
 pub use global::GlobalAction;
 pub use help::HelpAction;
+pub use issues::IssuesAction;
+pub use marks::MarksAction;
+pub use prompt::PromptAction;
+pub use sessions::SessionsAction;
+pub use stats::StatsAction;
 
 /// Unified action type
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,5 +25,49 @@ pub enum Action {
     Blame(BlameAction),
     Diff(DiffAction),
     Help(HelpAction),
-    None,
+    Stats(StatsAction),
+    Sessions(SessionsAction),
+    Marks(MarksAction),
+    Issues(IssuesAction),
+    Prompt(PromptAction),
+    /// Run the user command with this name
+    Command(String),
+}
+
+impl Action {
+    /// Cursor, scroll and trail movements, which a count prefix repeats
+    pub fn is_motion(&self) -> bool {
+        matches!(
+            self,
+            Action::Blame(
+                BlameAction::CursorUp
+                    | BlameAction::CursorDown
+                    | BlameAction::Cursor10Up
+                    | BlameAction::Cursor10Down
+                    | BlameAction::CursorPageUp
+                    | BlameAction::CursorPageDown
+                    | BlameAction::NextHunk
+                    | BlameAction::PreviousHunk
+                    | BlameAction::DrillDown
+                    | BlameAction::GoBack
+            ) | Action::Diff(
+                DiffAction::ScrollUp
+                    | DiffAction::ScrollDown
+                    | DiffAction::Scroll10Up
+                    | DiffAction::Scroll10Down
+                    | DiffAction::ScrollPageUp
+                    | DiffAction::ScrollPageDown
+                    | DiffAction::ScrollLeft
+                    | DiffAction::ScrollRight
+            ) | Action::Help(
+                HelpAction::ScrollUp
+                    | HelpAction::ScrollDown
+                    | HelpAction::Scroll10Up
+                    | HelpAction::Scroll10Down
+            ) | Action::Stats(StatsAction::CursorUp | StatsAction::CursorDown)
+                | Action::Sessions(SessionsAction::CursorUp | SessionsAction::CursorDown)
+                | Action::Marks(MarksAction::CursorUp | MarksAction::CursorDown)
+                | Action::Issues(IssuesAction::CursorUp | IssuesAction::CursorDown)
+        )
+    }
 }
@@ -0,0 +1,16 @@
+/// Text editing actions for the status bar prompt; these keys are fixed, not configurable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptAction {
+    Insert(char),
+    Backspace,
+    /// Delete the word before the cursor (Ctrl-W)
+    DeleteWord,
+    /// Complete the word being typed, cycling through matches (Tab, BackTab)
+    Complete,
+    CompletePrevious,
+    /// Show the previous or next command line from history (Up, Down)
+    HistoryPrevious,
+    HistoryNext,
+    Submit,
+    Cancel,
+}
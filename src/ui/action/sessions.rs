@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Actions for the saved sessions list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SessionsAction {
+    CursorUp,
+    CursorDown,
+    /// Rebuild the selected session's frames
+    Select,
+    Delete,
+    Close,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Actions for Stats mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum StatsAction {
+    CursorUp,
+    CursorDown,
+    /// Highlight the selected author's or commit's lines, or clear it if already highlighted
+    Select,
+    Close,
+}
@@ -1,12 +1,31 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
-use crate::application::port::DiffFormatter;
-use crate::config::AppConfig;
-use crate::domain::{BlameStack, CommitHash, CommitInfo, GitGateway};
-use crate::ui::action::{Action, BlameAction, DiffAction, GlobalAction, HelpAction};
+use crate::application::port::{Clipboard, DiffFormatter, SessionStore};
+use crate::commands::{self, CommandContext};
+use crate::config::{
+    AppConfig, BlockStyle, Coloring, ColumnKind, CommandOutput, ExportFormat, KeyBinding,
+};
+use crate::domain::blame::AuthorLines;
+use crate::domain::date;
+use crate::domain::{
+    BlameStack, CommitHash, CommitInfo, GitGateway, Mark, Owner, OwnershipStats, Session,
+};
+use crate::export;
+use crate::forge::{Forge, ForgeError, PullRequest};
+use crate::issues::{IssueHighlight, IssueLinker, IssueRef};
+use crate::ui::action::{
+    Action, BlameAction, DiffAction, GlobalAction, HelpAction, IssuesAction, MarksAction,
+    PromptAction, SessionsAction, StatsAction,
+};
+use crate::ui::command_line::{self, Command, Completion, Flag, History, Setting};
+use crate::ui::event::PendingKeys;
+use crate::ui::highlight::Highlighter;
 use crate::ui::mode::Mode;
+use crate::ui::prompt::{Prompt, PromptKind};
+use crate::ui::theme::{AuthorSlots, Theme};
 use crate::ui::widget::HelpView;
 
 /// Layout state
@@ -16,35 +35,130 @@ pub enum LayoutState {
     Split { ratio: u16 },
 }
 
+/// A file and line to open in the editor once the terminal is released
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditRequest {
+    pub path: PathBuf,
+    pub line: usize,
+    /// The file is the working tree's, so the frame is blamed again once the editor exits
+    pub working_file: bool,
+}
+
+/// An expanded user command for the main loop to run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandRequest {
+    pub name: String,
+    pub line: String,
+    pub output: CommandOutput,
+    /// Working directory, the repository root
+    pub dir: Option<PathBuf>,
+}
+
+/// What a yank action copies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Yank {
+    Hash,
+    ShortHash,
+    Line,
+    Subject,
+    Permalink,
+    LineLink,
+    HunkLink,
+}
+
 /// Application state
-pub struct App<G: GitGateway, F: DiffFormatter> {
+pub struct App<G: GitGateway, F: DiffFormatter, S: SessionStore, C: Clipboard> {
     // Dependencies
     git: G,
     formatter: F,
+    sessions: S,
+    clipboard: C,
 
     // Config
     pub config: AppConfig,
+    pub theme: Theme,
+    /// Syntax highlighter for the content column, unless disabled or under `NO_COLOR`
+    pub highlighter: Option<Highlighter>,
+    /// Issue references matched by the `[[issues]]` trackers
+    pub issue_linker: IssueLinker,
 
     // State
     pub mode: Mode,
     pub blame_stack: BlameStack,
     pub diff_lines: Option<Vec<String>>,
     pub diff_commit_info: Option<CommitInfo>,
+    pub diff_pull_request: Option<PullRequest>,
+    /// Title of the diff pane when it shows a command's output
+    pub pane_title: Option<String>,
 
     // UI state
     pub layout: LayoutState,
     pub diff_selected_line: usize,
     pub help_scroll: usize,
     pub help_selected_line: usize,
+    /// Ownership of the current frame, computed when the overlay opens
+    pub stats: Option<OwnershipStats>,
+    /// Selected author or commit in the ownership overlay
+    pub stats_selected: usize,
+    /// Author or commit whose lines are highlighted in the blame view
+    pub owner_highlight: Option<Owner>,
+    /// Saved sessions, loaded when the list is opened
+    pub session_list: Vec<Session>,
+    /// The exploration continues a saved session, so saving it may shorten the trail
+    resumed: bool,
+    pub sessions_selected: usize,
+    /// Marks of this repository, sorted by name
+    pub marks: Vec<Mark>,
+    pub marks_selected: usize,
+    /// Issue references of the commit the list was opened on
+    pub issues: Vec<IssueRef>,
+    pub issues_selected: usize,
+    /// Issue whose commits are highlighted in the blame view
+    pub issue_highlight: Option<IssueHighlight>,
+    /// Show the commit subject in place of the author column
+    pub show_subject: bool,
+    pub coloring: Coloring,
+    pub block_style: BlockStyle,
+    /// Show the overlay listing authors and their line counts
+    pub show_author_legend: bool,
+    /// Authors of the current frame, computed once per frame while author colours are shown
+    pub authors: Vec<AuthorLines>,
+    /// Revision and path `authors` was computed for
+    authors_frame: Option<(CommitHash, PathBuf)>,
+    /// Author colours, kept for the whole session so authors keep theirs across frames
+    pub author_slots: AuthorSlots,
+    /// Wrap long lines in the diff pane
+    pub diff_wrap: bool,
+    /// Status bar text input, active in `Mode::Prompt`
+    pub prompt: Option<Prompt>,
+    /// Mode the prompt was opened from, restored when it closes
+    pub prompt_origin: Mode,
+    /// Matches for the word being typed on the command line
+    pub completion: Completion,
+    command_history: History,
+    /// Count and keys of a binding still being typed
+    pub pending_keys: PendingKeys,
     pub previous_mode: Mode,
     pub status_message: Option<String>,
+    /// Set by actions that need the editor; the main loop suspends the TUI to run it
+    pub edit_request: Option<EditRequest>,
+    /// Set by user command keys; the main loop runs it according to its output
+    pub command_request: Option<CommandRequest>,
 
     // Flags
     pub should_quit: bool,
 }
 
-impl<G: GitGateway, F: DiffFormatter> App<G, F> {
-    pub fn new(git: G, formatter: F, config: AppConfig, file_path: PathBuf) -> Result<Self> {
+impl<G: GitGateway, F: DiffFormatter, S: SessionStore, C: Clipboard> App<G, F, S, C> {
+    pub fn new(
+        git: G,
+        formatter: F,
+        sessions: S,
+        clipboard: C,
+        config: AppConfig,
+        file_path: PathBuf,
+        revision: &CommitHash,
+    ) -> Result<Self> {
         // Check if delta is available
         if !formatter.is_available() {
             anyhow::bail!(
@@ -53,26 +167,131 @@ impl<G: GitGateway, F: DiffFormatter> App<G, F> {
         }
 
         // Get initial blame
-        let initial_frame = git.blame(&file_path, &CommitHash::head())?;
+        let initial_frame = git.blame(&file_path, revision)?;
         let mut blame_stack = BlameStack::new();
         blame_stack.push(initial_frame);
 
-        Ok(Self {
+        let theme = Theme::from_config(&config.theme);
+        let coloring = config.blame.coloring;
+        let block_style = config.blame.blocks;
+        let diff_wrap = config.diff.wrap;
+
+        let syntax = &config.blame.syntax;
+        let highlighter = if syntax.enabled && !theme.no_color {
+            let theme_name = syntax.theme.as_deref().unwrap_or(theme.syntax_theme);
+            Some(Highlighter::new(theme_name).map_err(anyhow::Error::msg)?)
+        } else {
+            None
+        };
+
+        let issue_linker =
+            IssueLinker::new(&config.issues).context("Invalid pattern in [[issues]]")?;
+
+        // A damaged marks file shouldn't stop blake from starting
+        let (marks, status_message) = match sessions.load_marks() {
+            Ok(marks) => (marks, None),
+            Err(e) => (Vec::new(), Some(format!("Failed to load marks: {}", e))),
+        };
+
+        let mut app = Self {
             git,
             formatter,
+            sessions,
+            clipboard,
             config,
+            theme,
+            highlighter,
+            issue_linker,
             mode: Mode::Blame,
             blame_stack,
             diff_lines: None,
             diff_commit_info: None,
+            diff_pull_request: None,
+            pane_title: None,
             layout: LayoutState::FullScreen,
             diff_selected_line: 0,
             help_scroll: 0,
             help_selected_line: 0,
+            stats: None,
+            stats_selected: 0,
+            owner_highlight: None,
+            session_list: Vec::new(),
+            resumed: false,
+            sessions_selected: 0,
+            marks,
+            marks_selected: 0,
+            issues: Vec::new(),
+            issues_selected: 0,
+            issue_highlight: None,
+            show_subject: false,
+            coloring,
+            block_style,
+            show_author_legend: false,
+            authors: Vec::new(),
+            authors_frame: None,
+            author_slots: AuthorSlots::default(),
+            diff_wrap,
+            prompt: None,
+            prompt_origin: Mode::Blame,
+            completion: Completion::default(),
+            command_history: History::default(),
+            pending_keys: PendingKeys::default(),
             previous_mode: Mode::Blame,
-            status_message: None,
+            status_message,
+            edit_request: None,
+            command_request: None,
             should_quit: false,
-        })
+        };
+        app.highlight_current();
+        Ok(app)
+    }
+
+    /// Feed a key press through the keymap, running any binding it completes
+    pub fn handle_key(&mut self, key: KeyBinding) -> Result<()> {
+        let actions = self.pending_keys.push(key, self.mode, &self.config.keymap);
+        for (action, count) in actions {
+            self.dispatch_counted(action, count)?;
+        }
+        Ok(())
+    }
+
+    /// Run what an unfinished binding means alone once `key_timeout_ms` has passed
+    pub fn expire_keys(&mut self) -> Result<()> {
+        let timeout = Duration::from_millis(self.config.general.key_timeout_ms);
+        match self.pending_keys.expire(timeout) {
+            Some((action, count)) => self.dispatch_counted(action, count),
+            None => Ok(()),
+        }
+    }
+
+    /// Run an action typed after a count: jumps go to that line, motions repeat
+    pub fn dispatch_counted(&mut self, action: Action, count: Option<usize>) -> Result<()> {
+        let Some(count) = count else {
+            return self.dispatch(action);
+        };
+        match action {
+            Action::Blame(BlameAction::CursorTop | BlameAction::CursorBottom) => {
+                let total = self.blame_stack.current().map_or(0, |f| f.entries.len());
+                if total > 0 {
+                    self.goto_line(count.clamp(1, total))?;
+                }
+                Ok(())
+            }
+            Action::Diff(DiffAction::ScrollTop | DiffAction::ScrollBottom) => {
+                let total = self.diff_lines.as_ref().map_or(0, Vec::len);
+                if total > 0 {
+                    self.goto_line(count.clamp(1, total))?;
+                }
+                Ok(())
+            }
+            action if action.is_motion() => {
+                for _ in 0..count {
+                    self.dispatch(action.clone())?;
+                }
+                Ok(())
+            }
+            action => self.dispatch(action),
+        }
     }
 
     pub fn dispatch(&mut self, action: Action) -> Result<()> {
@@ -81,7 +300,12 @@ impl<G: GitGateway, F: DiffFormatter> App<G, F> {
             Action::Blame(ba) => self.handle_blame(ba),
             Action::Diff(da) => self.handle_diff(da),
             Action::Help(ha) => self.handle_help(ha),
-            Action::None => Ok(()),
+            Action::Stats(sa) => self.handle_stats(sa),
+            Action::Sessions(sa) => self.handle_sessions(sa),
+            Action::Marks(ma) => self.handle_marks(ma),
+            Action::Issues(ia) => self.handle_issues(ia),
+            Action::Prompt(pa) => self.handle_prompt(pa),
+            Action::Command(name) => self.run_command(&name),
         }
     }
 
@@ -97,6 +321,11 @@ impl<G: GitGateway, F: DiffFormatter> App<G, F> {
                     self.mode = Mode::Help;
                 }
             }
+            GlobalAction::CommandLine => {
+                self.command_history.reset();
+                self.open_prompt(Prompt::new(PromptKind::Command, ""));
+                self.completion = Completion::new("", self.prompt_origin, &self.config.commands);
+            }
         }
         Ok(())
     }
@@ -141,6 +370,16 @@ impl<G: GitGateway, F: DiffFormatter> App<G, F> {
             BlameAction::CursorBottom => {
                 frame.selected_line = total.saturating_sub(1);
             }
+            BlameAction::NextHunk => {
+                if let Some(line) = frame.next_hunk_start(frame.selected_line) {
+                    frame.selected_line = line;
+                }
+            }
+            BlameAction::PreviousHunk => {
+                if let Some(line) = frame.previous_hunk_start(frame.selected_line) {
+                    frame.selected_line = line;
+                }
+            }
             BlameAction::DrillDown => {
                 self.drill_down()?;
             }
@@ -150,6 +389,73 @@ impl<G: GitGateway, F: DiffFormatter> App<G, F> {
             BlameAction::ShowDiff => {
                 self.show_diff()?;
             }
+            BlameAction::ShowStats => {
+                let stats = OwnershipStats::new(frame, date::now());
+                self.author_slots
+                    .assign(&stats.authors, self.theme.author_palette.len());
+                self.stats = Some(stats);
+                self.stats_selected = 0;
+                self.mode = Mode::Stats;
+            }
+            BlameAction::ShowSessions => match self.sessions.list() {
+                Ok(list) => {
+                    self.session_list = list;
+                    self.sessions_selected = 0;
+                    self.mode = Mode::Sessions;
+                }
+                Err(e) => {
+                    self.status_message = Some(format!("Failed to list sessions: {}", e));
+                }
+            },
+            BlameAction::YankHash => self.yank(Yank::Hash),
+            BlameAction::YankShortHash => self.yank(Yank::ShortHash),
+            BlameAction::YankLine => self.yank(Yank::Line),
+            BlameAction::YankSubject => self.yank(Yank::Subject),
+            BlameAction::YankPermalink => self.yank(Yank::Permalink),
+            BlameAction::YankLineLink => self.yank(Yank::LineLink),
+            BlameAction::YankHunkLink => self.yank(Yank::HunkLink),
+            BlameAction::OpenLineLink => self.open_line_link(false),
+            BlameAction::OpenHunkLink => self.open_line_link(true),
+            BlameAction::OpenPullRequest => self.open_pull_request()?,
+            BlameAction::ShowIssues => self.show_issues()?,
+            BlameAction::HighlightIssue => {
+                let id = self
+                    .issue_highlight
+                    .as_ref()
+                    .map(|h| h.id.clone())
+                    .unwrap_or_default();
+                self.open_prompt(Prompt::new(PromptKind::HighlightIssue, id));
+            }
+            BlameAction::ShowMarks => {
+                self.marks_selected = 0;
+                self.mode = Mode::Marks;
+            }
+            BlameAction::SetMark => {
+                self.open_prompt(Prompt::new(PromptKind::SetMark, ""));
+            }
+            BlameAction::JumpToMark => {
+                self.open_prompt(Prompt::new(PromptKind::JumpToMark, ""));
+            }
+            BlameAction::OpenInEditor => self.open_in_editor(),
+            BlameAction::ExportTrail => {
+                let file_name = format!("blake-trail.{}", self.config.export.format.extension());
+                self.open_prompt(Prompt::new(PromptKind::ExportTrail, file_name));
+            }
+            BlameAction::ToggleAuthorSubject => {
+                self.show_subject = !self.show_subject;
+                self.check_author_column();
+            }
+            BlameAction::CycleColoring => {
+                self.coloring = self.coloring.next();
+                self.status_message = Some(format!("Colouring: {:?}", self.coloring));
+            }
+            BlameAction::ToggleAuthorLegend => {
+                self.show_author_legend = !self.show_author_legend;
+            }
+            BlameAction::CycleBlockStyle => {
+                self.block_style = self.block_style.next();
+                self.status_message = Some(format!("Hunk grouping: {:?}", self.block_style));
+            }
         }
         Ok(())
     }
@@ -194,14 +500,154 @@ impl<G: GitGateway, F: DiffFormatter> App<G, F> {
                 self.layout = LayoutState::FullScreen;
                 self.mode = Mode::Blame;
             }
-            DiffAction::OpenInGitHub => {
-                self.open_in_github();
+            DiffAction::OpenInBrowser => {
+                self.open_in_browser();
+            }
+            DiffAction::OpenPullRequest => self.open_pull_request()?,
+            DiffAction::ShowIssues => self.show_issues()?,
+            DiffAction::YankHash => self.yank(Yank::Hash),
+            DiffAction::YankShortHash => self.yank(Yank::ShortHash),
+            DiffAction::YankSubject => self.yank(Yank::Subject),
+            DiffAction::YankPermalink => self.yank(Yank::Permalink),
+        }
+        Ok(())
+    }
+
+    /// Copy from the diff's commit in diff mode, otherwise from the selected blame line
+    fn yank(&mut self, yank: Yank) {
+        let (hash, subject, line) = if self.mode == Mode::Diff {
+            let Some(info) = &self.diff_commit_info else {
+                self.status_message = Some("No commit selected".to_string());
+                return;
+            };
+            let subject = info.message.lines().next().unwrap_or_default();
+            (info.hash.clone(), subject.to_string(), None)
+        } else {
+            let Some(entry) = self
+                .blame_stack
+                .current()
+                .and_then(|f| f.entries.get(f.selected_line))
+            else {
+                return;
+            };
+            (
+                entry.commit_hash.clone(),
+                entry.summary.clone(),
+                Some(entry.content.clone()),
+            )
+        };
+
+        let (label, text) = match yank {
+            Yank::Hash => ("commit hash", hash.to_string()),
+            Yank::ShortHash => ("short hash", hash.short().to_string()),
+            Yank::Line => ("line", line.unwrap_or_default()),
+            Yank::Subject => ("subject", subject),
+            Yank::Permalink => match self.forge() {
+                Ok(forge) => ("permalink", forge.commit_url(&hash)),
+                Err(e) => {
+                    self.status_message = Some(e.to_string());
+                    return;
+                }
+            },
+            Yank::LineLink | Yank::HunkLink => {
+                match self.line_link(matches!(yank, Yank::HunkLink)) {
+                    Ok(url) => ("permalink", url),
+                    Err(e) => {
+                        self.status_message = Some(e.to_string());
+                        return;
+                    }
+                }
+            }
+        };
+
+        self.status_message = Some(match self.clipboard.copy(&text) {
+            Ok(()) => {
+                let preview: String = text.trim().chars().take(48).collect();
+                format!("Copied {}: {}", label, preview)
+            }
+            Err(e) => format!("Copy failed: {}", e),
+        });
+    }
+
+    /// The forge of the configured remote, resolved on each use so remote changes are picked up
+    fn forge(&self) -> Result<Forge, ForgeError> {
+        Forge::resolve(&self.git.remotes(), &self.config.forge)
+    }
+
+    /// Link to the current frame's file at its revision, anchored at the selected line
+    /// or at the blame hunk around it
+    fn line_link(&self, hunk: bool) -> Result<String> {
+        let frame = self.blame_stack.current().context("No file loaded")?;
+        let forge = self.forge()?;
+        let selected = frame.selected_line;
+        let (start, end) = frame
+            .hunks
+            .iter()
+            .find(|h| hunk && h.contains(&selected))
+            .map_or((selected, selected + 1), |h| (h.start, h.end));
+        Ok(forge.blob_url(&frame.commit_hash, &frame.file_path, (start + 1, end)))
+    }
+
+    fn open_line_link(&mut self, hunk: bool) {
+        match self.line_link(hunk) {
+            Ok(url) => {
+                if let Err(e) = open::that(url) {
+                    self.status_message = Some(format!("Failed to open browser: {}", e));
+                }
+            }
+            Err(e) => {
+                self.status_message = Some(e.to_string());
+            }
+        }
+    }
+
+    /// The diff's commit in diff mode, otherwise the selected line's commit
+    fn selected_commit_info(&self) -> Result<Option<CommitInfo>> {
+        if self.mode == Mode::Diff {
+            return Ok(self.diff_commit_info.clone());
+        }
+        let hash = self
+            .blame_stack
+            .current()
+            .and_then(|f| f.entries.get(f.selected_line))
+            .map(|e| e.commit_hash.clone());
+        match hash {
+            Some(hash) => Ok(Some(self.git.commit_info(&hash)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Open the pull request named by the diff's commit or the selected line's commit
+    fn open_pull_request(&mut self) -> Result<()> {
+        let Some(info) = self.selected_commit_info()? else {
+            self.status_message = Some("No commit selected".to_string());
+            return Ok(());
+        };
+
+        let forge = match self.forge() {
+            Ok(forge) => forge,
+            Err(e) => {
+                self.status_message = Some(e.to_string());
+                return Ok(());
+            }
+        };
+        match forge.pull_request(&info.message) {
+            Some(pr) => {
+                if let Err(e) = open::that(&pr.url) {
+                    self.status_message = Some(format!("Failed to open browser: {}", e));
+                }
+            }
+            None => {
+                self.status_message = Some(format!(
+                    "No pull request referenced by {}",
+                    info.hash.short()
+                ));
             }
         }
         Ok(())
     }
 
-    fn open_in_github(&mut self) {
+    fn open_in_browser(&mut self) {
         let commit_hash = match &self.diff_commit_info {
             Some(info) => &info.hash,
             None => {
@@ -210,20 +656,22 @@ impl<G: GitGateway, F: DiffFormatter> App<G, F> {
             }
         };
 
-        match self.git.github_commit_url(commit_hash) {
-            Some(url) => {
-                if let Err(e) = open::that(&url) {
+        match self.forge() {
+            Ok(forge) => {
+                if let Err(e) = open::that(forge.commit_url(commit_hash)) {
                     self.status_message = Some(format!("Failed to open browser: {}", e));
                 }
             }
-            None => {
-                self.status_message = Some("Not a GitHub repository".to_string());
+            Err(e) => {
+                self.status_message = Some(e.to_string());
             }
         }
     }
 
     fn handle_help(&mut self, action: HelpAction) -> Result<()> {
-        let total = HelpView::new(&self.config.keymap).line_count();
+        let total = HelpView::new(&self.config.keymap, &self.theme)
+            .with_commands(&self.config.commands)
+            .line_count();
 
         match action {
             HelpAction::ScrollUp => {
@@ -248,6 +696,785 @@ impl<G: GitGateway, F: DiffFormatter> App<G, F> {
         Ok(())
     }
 
+    fn handle_stats(&mut self, action: StatsAction) -> Result<()> {
+        let owners = self
+            .stats
+            .as_ref()
+            .map(OwnershipStats::owners)
+            .unwrap_or_default();
+
+        match action {
+            StatsAction::CursorUp => {
+                self.stats_selected = self.stats_selected.saturating_sub(1);
+            }
+            StatsAction::CursorDown => {
+                if self.stats_selected < owners.len().saturating_sub(1) {
+                    self.stats_selected += 1;
+                }
+            }
+            StatsAction::Select => {
+                if let Some(owner) = owners.get(self.stats_selected) {
+                    self.owner_highlight = if self.owner_highlight.as_ref() == Some(owner) {
+                        None
+                    } else {
+                        Some(owner.clone())
+                    };
+                    self.stats = None;
+                    self.mode = Mode::Blame;
+                }
+            }
+            StatsAction::Close => {
+                self.stats = None;
+                self.mode = Mode::Blame;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_sessions(&mut self, action: SessionsAction) -> Result<()> {
+        match action {
+            SessionsAction::CursorUp => {
+                self.sessions_selected = self.sessions_selected.saturating_sub(1);
+            }
+            SessionsAction::CursorDown => {
+                if self.sessions_selected < self.session_list.len().saturating_sub(1) {
+                    self.sessions_selected += 1;
+                }
+            }
+            SessionsAction::Select => {
+                if let Some(session) = self.session_list.get(self.sessions_selected).cloned() {
+                    // Keep the current exploration before replacing it
+                    let saved = self.save_session();
+                    self.restore_session(&session);
+                    if let Err(e) = saved {
+                        self.status_message = Some(format!("Failed to save session: {}", e));
+                    }
+                    self.mode = Mode::Blame;
+                }
+            }
+            SessionsAction::Delete => {
+                if self.sessions_selected < self.session_list.len() {
+                    let file = &self.session_list[self.sessions_selected].file;
+                    match self.sessions.delete(file) {
+                        Ok(()) => {
+                            self.session_list.remove(self.sessions_selected);
+                            self.sessions_selected = self
+                                .sessions_selected
+                                .min(self.session_list.len().saturating_sub(1));
+                        }
+                        Err(e) => {
+                            self.status_message = Some(format!("Failed to delete session: {}", e));
+                        }
+                    }
+                }
+            }
+            SessionsAction::Close => {
+                self.mode = Mode::Blame;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_issues(&mut self, action: IssuesAction) -> Result<()> {
+        match action {
+            IssuesAction::CursorUp => {
+                self.issues_selected = self.issues_selected.saturating_sub(1);
+            }
+            IssuesAction::CursorDown => {
+                if self.issues_selected < self.issues.len().saturating_sub(1) {
+                    self.issues_selected += 1;
+                }
+            }
+            IssuesAction::Select => {
+                if let Some(issue) = self.issues.get(self.issues_selected) {
+                    self.mode = self.previous_mode;
+                    if let Err(e) = open::that(&issue.url) {
+                        self.status_message = Some(format!("Failed to open browser: {}", e));
+                    }
+                }
+            }
+            IssuesAction::Highlight => {
+                if let Some(issue) = self.issues.get(self.issues_selected) {
+                    let id = issue.id.clone();
+                    self.mode = self.previous_mode;
+                    self.highlight_issue(&id);
+                }
+            }
+            IssuesAction::Close => {
+                self.mode = self.previous_mode;
+            }
+        }
+        Ok(())
+    }
+
+    /// List the issue references of the diff's commit or the selected line's commit
+    fn show_issues(&mut self) -> Result<()> {
+        if self.issue_linker.is_empty() {
+            self.status_message =
+                Some("No issue trackers configured; add [[issues]] to the config".to_string());
+            return Ok(());
+        }
+        let Some(info) = self.selected_commit_info()? else {
+            self.status_message = Some("No commit selected".to_string());
+            return Ok(());
+        };
+
+        self.issues = self.issue_linker.distinct(&info.message);
+        if self.issues.is_empty() {
+            self.status_message = Some(format!("No issue references in {}", info.hash.short()));
+            return Ok(());
+        }
+        self.issues_selected = 0;
+        self.previous_mode = self.mode;
+        self.mode = Mode::Issues;
+        Ok(())
+    }
+
+    /// Highlight blame lines whose commit mentions `id`, or clear the highlight when empty
+    fn highlight_issue(&mut self, id: &str) {
+        let id = id.trim();
+        if id.is_empty() {
+            self.issue_highlight = None;
+            self.status_message = Some("Cleared issue highlight".to_string());
+            return;
+        }
+        self.issue_highlight = Some(IssueHighlight::new(id));
+        self.refresh_issue_highlight();
+
+        let lines = match (&self.issue_highlight, self.blame_stack.current()) {
+            (Some(highlight), Some(frame)) => frame
+                .entries
+                .iter()
+                .filter(|e| highlight.contains(&e.commit_hash))
+                .count(),
+            _ => 0,
+        };
+        self.status_message = Some(format!("{}: {} lines in this revision", id, lines));
+    }
+
+    /// Count the current frame's authors if it changed while author colours are shown
+    pub fn refresh_authors(&mut self) {
+        if self.coloring != Coloring::Author && !self.show_author_legend {
+            return;
+        }
+        let Some(frame) = self.blame_stack.current() else {
+            return;
+        };
+        let key = (frame.commit_hash.clone(), frame.file_path.clone());
+        if self.authors_frame.as_ref() == Some(&key) {
+            return;
+        }
+        self.authors = frame.authors();
+        self.author_slots
+            .assign(&self.authors, self.theme.author_palette.len());
+        self.authors_frame = Some(key);
+    }
+
+    /// Check the current frame's commits not yet seen against the highlighted issue
+    pub fn refresh_issue_highlight(&mut self) {
+        let (Some(highlight), Some(frame)) =
+            (self.issue_highlight.as_mut(), self.blame_stack.current())
+        else {
+            return;
+        };
+        for entry in &frame.entries {
+            if highlight.is_checked(&entry.commit_hash) {
+                continue;
+            }
+            // Unreadable commits just stay unhighlighted
+            let message = self
+                .git
+                .commit_message(&entry.commit_hash)
+                .unwrap_or_default();
+            highlight.record(&entry.commit_hash, &message);
+        }
+    }
+
+    fn handle_marks(&mut self, action: MarksAction) -> Result<()> {
+        match action {
+            MarksAction::CursorUp => {
+                self.marks_selected = self.marks_selected.saturating_sub(1);
+            }
+            MarksAction::CursorDown => {
+                if self.marks_selected < self.marks.len().saturating_sub(1) {
+                    self.marks_selected += 1;
+                }
+            }
+            MarksAction::Select => {
+                if let Some(mark) = self.marks.get(self.marks_selected).cloned() {
+                    self.mode = Mode::Blame;
+                    self.go_to_mark(&mark);
+                }
+            }
+            MarksAction::Delete => {
+                if self.marks_selected < self.marks.len() {
+                    let mark = self.marks.remove(self.marks_selected);
+                    self.marks_selected =
+                        self.marks_selected.min(self.marks.len().saturating_sub(1));
+                    self.status_message = Some(match self.sessions.save_marks(&self.marks) {
+                        Ok(()) => format!("Deleted mark '{}'", mark.name),
+                        Err(e) => format!("Failed to save marks: {}", e),
+                    });
+                }
+            }
+            MarksAction::Close => {
+                self.mode = Mode::Blame;
+            }
+        }
+        Ok(())
+    }
+
+    fn set_mark(&mut self, name: char) {
+        if !Mark::is_valid_name(name) {
+            self.status_message = Some(format!("Invalid mark '{}': marks are a-z", name));
+            return;
+        }
+        let Some(frame) = self.blame_stack.current() else {
+            return;
+        };
+        let Some(entry) = frame.entries.get(frame.selected_line) else {
+            return;
+        };
+
+        let mark = Mark {
+            name,
+            path: frame.file_path.clone(),
+            revision: frame.commit_hash.to_string(),
+            line: entry.line_number,
+        };
+        let message = format!(
+            "Mark '{}' set at {}:{} @{}",
+            name,
+            mark.path.display(),
+            mark.line,
+            frame.commit_hash.short()
+        );
+        Mark::set(&mut self.marks, mark);
+        self.status_message = Some(match self.sessions.save_marks(&self.marks) {
+            Ok(()) => message,
+            Err(e) => format!("Failed to save marks: {}", e),
+        });
+    }
+
+    fn jump_to_mark(&mut self, name: char) {
+        match self.marks.iter().find(|m| m.name == name).cloned() {
+            Some(mark) => self.go_to_mark(&mark),
+            None => self.status_message = Some(format!("Mark '{}' is not set", name)),
+        }
+    }
+
+    /// Return to the mark's frame if it is in the trail, otherwise blame its revision as a new frame
+    fn go_to_mark(&mut self, mark: &Mark) {
+        let position = self
+            .blame_stack
+            .frames()
+            .iter()
+            .position(|f| f.commit_hash.as_str() == mark.revision && f.file_path == mark.path);
+
+        match position {
+            Some(index) => {
+                while self.blame_stack.depth() > index + 1 {
+                    self.blame_stack.pop();
+                }
+            }
+            None => {
+                let revision = CommitHash::new(mark.revision.clone());
+                match self.git.blame(&mark.path, &revision) {
+                    Ok(frame) => {
+                        self.blame_stack.push(frame);
+                        self.highlight_current();
+                    }
+                    Err(_) => {
+                        self.status_message = Some(format!(
+                            "Mark '{}': {} no longer exists at {}",
+                            mark.name,
+                            mark.path.display(),
+                            revision.short()
+                        ));
+                        return;
+                    }
+                }
+            }
+        }
+
+        if let Some(frame) = self.blame_stack.current_mut() {
+            frame.selected_line = frame
+                .entries
+                .iter()
+                .position(|e| e.line_number == mark.line)
+                .unwrap_or(frame.entries.len().saturating_sub(1));
+        }
+        self.status_message = Some(format!("Jumped to mark '{}'", mark.name));
+    }
+
+    /// Restore the session saved for the starting file, as with `--resume`
+    pub fn resume(&mut self) -> Result<()> {
+        let Some(file) = self
+            .blame_stack
+            .frames()
+            .first()
+            .map(|f| f.file_path.clone())
+        else {
+            return Ok(());
+        };
+        match self.sessions.load(&file)? {
+            Some(session) => self.restore_session(&session),
+            None => {
+                self.status_message = Some(format!("No saved session for {}", file.display()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Save the current exploration, keyed by the file it started from.
+    ///
+    /// A single frame is only saved when it continues a saved session, so opening a file
+    /// and quitting doesn't replace a deeper session for it.
+    pub fn save_session(&self) -> Result<()> {
+        if !self.config.general.save_sessions || (self.blame_stack.depth() <= 1 && !self.resumed) {
+            return Ok(());
+        }
+        if let Some(session) = Session::from_stack(&self.blame_stack, date::now()) {
+            self.sessions.save(&session)?;
+        }
+        Ok(())
+    }
+
+    /// Rebuild each saved frame, stopping at the first revision or path that no longer exists
+    fn restore_session(&mut self, session: &Session) {
+        let mut stack = BlameStack::new();
+        for saved in &session.frames {
+            let revision = CommitHash::new(saved.revision.clone());
+            let Ok(mut frame) = self.git.blame(&saved.path, &revision) else {
+                break;
+            };
+            frame.selected_line = saved
+                .selected_line
+                .min(frame.entries.len().saturating_sub(1));
+            frame.scroll_offset = saved.scroll_offset;
+            frame.drilled_through = saved.drilled_through.clone().map(CommitHash::new);
+            if let Some(highlighter) = self.highlighter.as_mut() {
+                highlighter.highlight(&frame);
+            }
+            stack.push(frame);
+        }
+
+        let restored = stack.depth();
+        let total = session.frames.len();
+        self.status_message = Some(if restored == 0 {
+            format!(
+                "Could not restore session for {}: revision no longer exists",
+                session.file.display()
+            )
+        } else if restored < total {
+            format!("Restored {} of {} frames", restored, total)
+        } else {
+            format!("Restored session: {} frames", total)
+        });
+        if restored > 0 {
+            self.blame_stack = stack;
+            self.owner_highlight = None;
+            self.resumed = true;
+        }
+    }
+
+    fn handle_prompt(&mut self, action: PromptAction) -> Result<()> {
+        let Some(prompt) = self.prompt.as_mut() else {
+            self.mode = self.prompt_origin;
+            return Ok(());
+        };
+        let is_command = prompt.kind == PromptKind::Command;
+
+        let submit = match action {
+            PromptAction::Insert(c) => {
+                prompt.insert(c);
+                prompt.kind.is_single_key()
+            }
+            PromptAction::Backspace => {
+                prompt.backspace();
+                false
+            }
+            PromptAction::DeleteWord => {
+                prompt.delete_word();
+                false
+            }
+            PromptAction::Complete => {
+                if is_command {
+                    self.completion.cycle(&mut prompt.input, true);
+                    prompt.error = None;
+                }
+                false
+            }
+            PromptAction::CompletePrevious => {
+                if is_command {
+                    self.completion.cycle(&mut prompt.input, false);
+                    prompt.error = None;
+                }
+                false
+            }
+            PromptAction::HistoryPrevious => {
+                if is_command && let Some(line) = self.command_history.older(&prompt.input) {
+                    prompt.replace(line);
+                }
+                false
+            }
+            PromptAction::HistoryNext => {
+                if is_command && let Some(line) = self.command_history.newer() {
+                    prompt.replace(line);
+                }
+                false
+            }
+            PromptAction::Cancel => {
+                self.prompt = None;
+                self.mode = self.prompt_origin;
+                false
+            }
+            PromptAction::Submit => true,
+        };
+
+        // Completions follow what is typed, but not what Tab inserted
+        let edited = !matches!(
+            action,
+            PromptAction::Complete
+                | PromptAction::CompletePrevious
+                | PromptAction::Cancel
+                | PromptAction::Submit
+        );
+        if is_command
+            && edited
+            && let Some(prompt) = &self.prompt
+        {
+            self.completion =
+                Completion::new(&prompt.input, self.prompt_origin, &self.config.commands);
+        }
+
+        if submit && let Some(prompt) = self.prompt.take() {
+            self.mode = self.prompt_origin;
+            let name = prompt.input.chars().next();
+            match (prompt.kind, name) {
+                (PromptKind::ExportTrail, _) => self.export_trail(&prompt.input),
+                (PromptKind::SetMark, Some(name)) => self.set_mark(name),
+                (PromptKind::JumpToMark, Some(name)) => self.jump_to_mark(name),
+                (PromptKind::SetMark | PromptKind::JumpToMark, None) => {}
+                (PromptKind::HighlightIssue, _) => self.highlight_issue(&prompt.input),
+                (PromptKind::EditRevisionCopy, Some('y' | 'Y')) => self.edit_revision_copy(),
+                (PromptKind::EditRevisionCopy, _) => {}
+                (PromptKind::Command, _) => self.submit_command_line(prompt.input),
+            }
+        }
+        Ok(())
+    }
+
+    fn open_prompt(&mut self, prompt: Prompt) {
+        self.prompt = Some(prompt);
+        self.prompt_origin = self.mode;
+        self.mode = Mode::Prompt;
+    }
+
+    /// Run a command line, reopening it with the error when it fails
+    fn submit_command_line(&mut self, input: String) {
+        if input.trim().is_empty() {
+            return;
+        }
+        self.command_history.record(&input);
+
+        let result = command_line::parse(&input, self.mode, &self.config.commands)
+            .map_err(anyhow::Error::msg)
+            .and_then(|command| self.run_command_line(command));
+        if let Err(e) = result {
+            self.open_prompt(
+                Prompt::new(PromptKind::Command, input.as_str()).with_error(e.to_string()),
+            );
+            self.completion = Completion::new(&input, self.prompt_origin, &self.config.commands);
+        }
+    }
+
+    fn run_command_line(&mut self, command: Command) -> Result<()> {
+        match command {
+            Command::Action(action) => self.dispatch(action),
+            Command::Goto(line) => self.goto_line(line),
+            Command::Rev(revision) => self.blame_revision(&revision),
+            Command::Set(settings) if settings.is_empty() => {
+                self.status_message = Some(self.settings_summary());
+                Ok(())
+            }
+            Command::Set(settings) => {
+                for setting in settings {
+                    self.apply_setting(setting);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Select a line by number: of the diff pane in diff mode, of the file otherwise
+    fn goto_line(&mut self, line: usize) -> Result<()> {
+        if self.mode == Mode::Diff {
+            let total = self.diff_lines.as_ref().map_or(0, Vec::len);
+            if line > total {
+                anyhow::bail!(
+                    "Line {} is past the end of the diff ({} lines)",
+                    line,
+                    total
+                );
+            }
+            self.diff_selected_line = line - 1;
+            return Ok(());
+        }
+
+        let frame = self.blame_stack.current_mut().context("No file loaded")?;
+        frame.selected_line = frame
+            .entries
+            .iter()
+            .position(|e| e.line_number == line)
+            .with_context(|| {
+                format!(
+                    "Line {} is past the end of {} ({} lines)",
+                    line,
+                    frame.file_path.display(),
+                    frame.entries.len()
+                )
+            })?;
+        Ok(())
+    }
+
+    /// Blame the current file at another revision as a new frame, staying on the same line number
+    fn blame_revision(&mut self, revision: &str) -> Result<()> {
+        let frame = self.blame_stack.current().context("No file loaded")?;
+        let line = frame
+            .entries
+            .get(frame.selected_line)
+            .map_or(1, |e| e.line_number);
+        let mut new_frame = self
+            .git
+            .blame(&frame.file_path, &CommitHash::from(revision))
+            .with_context(|| {
+                format!("Cannot blame {} at {}", frame.file_path.display(), revision)
+            })?;
+
+        new_frame.selected_line = line.min(new_frame.entries.len()).saturating_sub(1);
+        self.blame_stack.push(new_frame);
+        self.highlight_current();
+        Ok(())
+    }
+
+    fn apply_setting(&mut self, setting: Setting) {
+        match setting {
+            Setting::Flag(flag, value) => {
+                let current = match flag {
+                    Flag::Wrap => &mut self.diff_wrap,
+                    Flag::Subject => &mut self.show_subject,
+                    Flag::Legend => &mut self.show_author_legend,
+                };
+                *current = value.unwrap_or(!*current);
+                if flag == Flag::Subject {
+                    self.check_author_column();
+                }
+            }
+            Setting::Coloring(coloring) => self.coloring = coloring,
+            Setting::Blocks(style) => self.block_style = style,
+        }
+    }
+
+    /// The subject replaces the author column, so say so when the columns have none
+    fn check_author_column(&mut self) {
+        let has_author = self
+            .config
+            .blame
+            .columns
+            .iter()
+            .any(|c| c.kind == ColumnKind::Author);
+        if !has_author {
+            self.status_message =
+                Some("No Author column for subjects to replace; see [blame.columns]".to_string());
+        }
+    }
+
+    /// Current `:set` values, in the syntax `:set` accepts
+    fn settings_summary(&self) -> String {
+        let flag = |flag: Flag, on: bool| format!("{}{}", if on { "" } else { "no" }, flag.name());
+        format!(
+            "{} {} {} coloring={:?} blocks={:?}",
+            flag(Flag::Wrap, self.diff_wrap),
+            flag(Flag::Subject, self.show_subject),
+            flag(Flag::Legend, self.show_author_legend),
+            self.coloring,
+            self.block_style
+        )
+    }
+
+    /// Expand a user command against the selected line and hand it to the main loop
+    fn run_command(&mut self, name: &str) -> Result<()> {
+        let Some(command) = self.config.commands.get(name) else {
+            self.status_message = Some(format!("Unknown command: {}", name));
+            return Ok(());
+        };
+        let (template, output) = (command.command.clone(), command.output);
+
+        let mut context = CommandContext {
+            repo: self
+                .git
+                .workdir()
+                .map(Path::to_path_buf)
+                .unwrap_or_default(),
+            ..CommandContext::default()
+        };
+        if let Some(frame) = self.blame_stack.current() {
+            context.file = frame.file_path.clone();
+            if let Some(entry) = frame.entries.get(frame.selected_line) {
+                context.line = entry.line_number;
+                context.text = entry.content.clone();
+            }
+        }
+        if let Some(info) = self.selected_commit_info()? {
+            context.commit = info.hash.to_string();
+            context.parent = info
+                .parents
+                .first()
+                .map(|p| p.to_string())
+                .unwrap_or_default();
+        }
+
+        self.command_request = Some(CommandRequest {
+            name: name.to_string(),
+            line: commands::expand(&template, &context),
+            output,
+            dir: self.git.workdir().map(Path::to_path_buf),
+        });
+        Ok(())
+    }
+
+    /// Show a command's output in the diff pane
+    pub fn show_output(&mut self, name: &str, text: &str) {
+        self.diff_lines = Some(text.lines().map(str::to_string).collect());
+        self.diff_commit_info = None;
+        self.diff_pull_request = None;
+        self.pane_title = Some(name.to_string());
+        self.diff_selected_line = 0;
+        self.layout = LayoutState::Split { ratio: 50 };
+        self.mode = Mode::Diff;
+    }
+
+    /// Edit the working tree file when the frame shows the checked-out revision,
+    /// otherwise offer a read-only copy of the file at the frame's revision
+    fn open_in_editor(&mut self) {
+        let Some(frame) = self.blame_stack.current() else {
+            return;
+        };
+        let working_file = self.git.workdir().map(|root| root.join(&frame.file_path));
+
+        match working_file {
+            Some(path) if self.git.head().as_ref() == Some(&frame.commit_hash) && path.exists() => {
+                self.edit_request = Some(EditRequest {
+                    path,
+                    line: frame.selected_line + 1,
+                    working_file: true,
+                });
+            }
+            _ => self.open_prompt(Prompt::new(PromptKind::EditRevisionCopy, "")),
+        }
+    }
+
+    /// Blame the current frame again at HEAD, picking up commits made in the editor
+    pub fn refresh_after_edit(&mut self) {
+        let Some(frame) = self.blame_stack.current() else {
+            return;
+        };
+        match self.git.blame(&frame.file_path, &CommitHash::head()) {
+            Ok(mut new_frame) => {
+                let last_line = new_frame.entries.len().saturating_sub(1);
+                new_frame.selected_line = frame.selected_line.min(last_line);
+                new_frame.scroll_offset = frame.scroll_offset.min(last_line);
+                new_frame.drilled_through = frame.drilled_through.clone();
+                if let Some(frame) = self.blame_stack.current_mut() {
+                    *frame = new_frame;
+                }
+                self.highlight_current();
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Failed to refresh blame: {}", e));
+            }
+        }
+    }
+
+    /// Edit a read-only copy of the file at the frame's revision
+    fn edit_revision_copy(&mut self) {
+        let Some(frame) = self.blame_stack.current() else {
+            return;
+        };
+        let line = frame.selected_line + 1;
+        match self.revision_copy(&frame.commit_hash, &frame.file_path) {
+            Ok(path) => {
+                self.edit_request = Some(EditRequest {
+                    path,
+                    line,
+                    working_file: false,
+                })
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Failed to write revision copy: {}", e));
+            }
+        }
+    }
+
+    /// Path of a read-only copy of `file` at `commit` in the user's cache directory,
+    /// written on first use
+    fn revision_copy(&self, commit: &CommitHash, file: &Path) -> Result<PathBuf> {
+        // The full hash pins the content, so a copy written earlier can be reused
+        let path = dirs::cache_dir()
+            .context("No cache directory to write to")?
+            .join("blake")
+            .join("revisions")
+            .join(commit.as_str())
+            .join(file);
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        let content = self.git.file_at(commit, file)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&path, content)?;
+        let mut permissions = std::fs::metadata(&path)?.permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(&path, permissions)?;
+        Ok(path)
+    }
+
+    /// Write the trail report, reporting the outcome in the status bar
+    fn export_trail(&mut self, input: &str) {
+        let input = input.trim();
+        if input.is_empty() {
+            return;
+        }
+        let path = match input.strip_prefix("~/").zip(dirs::home_dir()) {
+            Some((rest, home)) => home.join(rest),
+            None => PathBuf::from(input),
+        };
+        let format = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .and_then(ExportFormat::from_extension)
+            .unwrap_or(self.config.export.format);
+
+        let result = export::trail(
+            &self.git,
+            &self.blame_stack,
+            self.config.export.include_diff,
+        )
+        .and_then(|steps| export::render(&steps, format, &self.config.diff.date))
+        .and_then(|report| Ok(std::fs::write(&path, report)?));
+        self.status_message = Some(match result {
+            Ok(()) => format!(
+                "Exported {} frames to {}",
+                self.blame_stack.depth(),
+                path.display()
+            ),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
     fn drill_down(&mut self) -> Result<()> {
         let (file_path, commit_hash) = {
             let frame = match self.blame_stack.current() {
@@ -260,20 +1487,31 @@ impl<G: GitGateway, F: DiffFormatter> App<G, F> {
                 None => return Ok(()),
             };
 
+            if entry.boundary {
+                self.status_message = Some(format!(
+                    "Line {} is older than the history boundary ^{}",
+                    entry.line_number,
+                    entry.commit_hash.short()
+                ));
+                return Ok(());
+            }
+
             (frame.file_path.clone(), entry.commit_hash.clone())
         };
 
         // Get parent commit from commit info
         let commit_info = self.git.commit_info(&commit_hash)?;
-        let parent = match commit_info.parent {
-            Some(p) => p,
+        let parent = match commit_info.parent() {
+            Some(p) => p.clone(),
             None => return Ok(()), // Initial commit, no parent to drill into
         };
 
         // Try to blame at parent commit - file may not exist there
         match self.git.blame(&file_path, &parent) {
-            Ok(new_frame) => {
+            Ok(mut new_frame) => {
+                new_frame.drilled_through = Some(commit_hash);
                 self.blame_stack.push(new_frame);
+                self.highlight_current();
                 self.status_message = None;
             }
             Err(_) => {
@@ -285,6 +1523,14 @@ impl<G: GitGateway, F: DiffFormatter> App<G, F> {
         Ok(())
     }
 
+    fn highlight_current(&mut self) {
+        if let (Some(highlighter), Some(frame)) =
+            (self.highlighter.as_mut(), self.blame_stack.current())
+        {
+            highlighter.highlight(frame);
+        }
+    }
+
     fn go_back(&mut self) {
         if self.blame_stack.depth() > 1 {
             self.blame_stack.pop();
@@ -311,7 +1557,12 @@ impl<G: GitGateway, F: DiffFormatter> App<G, F> {
         let lines = self.formatter.format(&diff)?;
 
         self.diff_lines = Some(lines);
+        self.diff_pull_request = self
+            .forge()
+            .ok()
+            .and_then(|forge| forge.pull_request(&commit_info.message));
         self.diff_commit_info = Some(commit_info);
+        self.pane_title = None;
         self.diff_selected_line = 0;
         self.layout = LayoutState::Split { ratio: 50 };
         self.mode = Mode::Diff;
@@ -0,0 +1,505 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use serde::de::value::StrDeserializer;
+use serde::de::{self, DeserializeOwned, Deserializer, IntoDeserializer, Visitor};
+
+use crate::config::{BlockStyle, Coloring, UserCommand};
+use crate::ui::action::{
+    Action, BlameAction, DiffAction, GlobalAction, HelpAction, IssuesAction, MarksAction,
+    SessionsAction, StatsAction,
+};
+use crate::ui::mode::Mode;
+
+/// Commands that take arguments, checked before action names
+const BUILTINS: [&str; 3] = ["goto", "rev", "set"];
+
+/// A parsed `:` command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// An action of the current mode or a global one, or a user command
+    Action(Action),
+    /// `goto <line>`: select a line of the blame, or of the diff pane in diff mode
+    Goto(usize),
+    /// `rev <revision>`: blame the current file at another revision
+    Rev(String),
+    /// `set [option...]`; without options, show the current values
+    Set(Vec<Setting>),
+}
+
+/// One `:set` argument
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    /// `wrap`, `nowrap` or `wrap!`; `None` toggles
+    Flag(Flag, Option<bool>),
+    Coloring(Coloring),
+    Blocks(BlockStyle),
+}
+
+/// On/off options of `:set`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flag {
+    /// Wrap long lines in the diff pane
+    Wrap,
+    /// Show the commit subject in place of the author column
+    Subject,
+    /// Show the author legend overlay
+    Legend,
+}
+
+impl Flag {
+    const ALL: [Flag; 3] = [Flag::Wrap, Flag::Subject, Flag::Legend];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Flag::Wrap => "wrap",
+            Flag::Subject => "subject",
+            Flag::Legend => "legend",
+        }
+    }
+}
+
+/// Parse a command line typed in `mode`
+pub fn parse(
+    input: &str,
+    mode: Mode,
+    commands: &HashMap<String, UserCommand>,
+) -> Result<Command, String> {
+    let mut words = input.split_whitespace();
+    let name = words.next().ok_or("Type a command, or Tab to list them")?;
+    let args: Vec<&str> = words.collect();
+
+    match name {
+        "goto" => match args[..] {
+            [line] => line
+                .parse()
+                .ok()
+                .filter(|&line| line > 0)
+                .map(Command::Goto)
+                .ok_or_else(|| format!("Not a line number: {}", line)),
+            _ => Err("Usage: goto <line>".to_string()),
+        },
+        "rev" => match args[..] {
+            [revision] => Ok(Command::Rev(revision.to_string())),
+            _ => Err("Usage: rev <revision>".to_string()),
+        },
+        "set" => args
+            .iter()
+            .map(|arg| parse_setting(arg))
+            .collect::<Result<_, _>>()
+            .map(Command::Set),
+        _ => {
+            let action = action(name, mode).or_else(|| {
+                commands
+                    .contains_key(name)
+                    .then(|| Action::Command(name.to_string()))
+            });
+            match action {
+                Some(_) if !args.is_empty() => Err(format!("{} takes no arguments", name)),
+                Some(action) => Ok(Command::Action(action)),
+                None => Err(format!("Unknown command: {}", name)),
+            }
+        }
+    }
+}
+
+fn parse_setting(arg: &str) -> Result<Setting, String> {
+    if let Some((name, value)) = arg.split_once('=') {
+        return match name {
+            "coloring" => variant(value)
+                .map(Setting::Coloring)
+                .ok_or_else(|| unknown_value::<Coloring>(name, value)),
+            "blocks" => variant(value)
+                .map(Setting::Blocks)
+                .ok_or_else(|| unknown_value::<BlockStyle>(name, value)),
+            _ => Err(format!("Unknown option: {}", name)),
+        };
+    }
+    if matches!(arg, "coloring" | "blocks") {
+        return Err(format!("{} needs a value, e.g. {}=Heatmap", arg, arg));
+    }
+
+    let (name, value) = if let Some(name) = arg.strip_suffix('!') {
+        (name, None)
+    } else if let Some(name) = arg.strip_prefix("no") {
+        (name, Some(false))
+    } else {
+        (arg, Some(true))
+    };
+    Flag::ALL
+        .into_iter()
+        .find(|flag| flag.name() == name)
+        .map(|flag| Setting::Flag(flag, value))
+        .ok_or_else(|| format!("Unknown option: {}", arg))
+}
+
+fn unknown_value<T: DeserializeOwned + Debug>(name: &str, value: &str) -> String {
+    format!(
+        "Unknown {}: {} (expected {})",
+        name,
+        value,
+        variants::<T>().join(", ")
+    )
+}
+
+/// The action named `name` in `mode`, falling back to global actions
+fn action(name: &str, mode: Mode) -> Option<Action> {
+    let action = match mode {
+        Mode::Blame => variant(name).map(Action::Blame),
+        Mode::Diff => variant(name).map(Action::Diff),
+        Mode::Help => variant(name).map(Action::Help),
+        Mode::Stats => variant(name).map(Action::Stats),
+        Mode::Sessions => variant(name).map(Action::Sessions),
+        Mode::Marks => variant(name).map(Action::Marks),
+        Mode::Issues => variant(name).map(Action::Issues),
+        Mode::Prompt => None,
+    };
+    action.or_else(|| variant(name).map(Action::Global))
+}
+
+/// Every command available in `mode`: built-ins, the mode's actions, global actions and user commands
+pub fn names(mode: Mode, commands: &HashMap<String, UserCommand>) -> Vec<String> {
+    let actions = match mode {
+        Mode::Blame => variants::<BlameAction>(),
+        Mode::Diff => variants::<DiffAction>(),
+        Mode::Help => variants::<HelpAction>(),
+        Mode::Stats => variants::<StatsAction>(),
+        Mode::Sessions => variants::<SessionsAction>(),
+        Mode::Marks => variants::<MarksAction>(),
+        Mode::Issues => variants::<IssuesAction>(),
+        Mode::Prompt => Vec::new(),
+    };
+    BUILTINS
+        .into_iter()
+        .chain(actions)
+        .chain(variants::<GlobalAction>())
+        .map(str::to_string)
+        .chain(commands.keys().cloned())
+        .collect()
+}
+
+/// Arguments `:set` accepts
+fn setting_names() -> Vec<String> {
+    let mut names = Vec::new();
+    for flag in Flag::ALL {
+        names.push(flag.name().to_string());
+        names.push(format!("no{}", flag.name()));
+    }
+    for coloring in variants::<Coloring>() {
+        names.push(format!("coloring={}", coloring));
+    }
+    for style in variants::<BlockStyle>() {
+        names.push(format!("blocks={}", style));
+    }
+    names
+}
+
+/// The variant of a unit-only serde enum named `name`, ignoring case
+fn variant<T: DeserializeOwned + Debug>(name: &str) -> Option<T> {
+    let name = variants::<T>()
+        .into_iter()
+        .find(|v| v.eq_ignore_ascii_case(name))?;
+    let deserializer: StrDeserializer<'_, de::value::Error> = name.into_deserializer();
+    T::deserialize(deserializer).ok()
+}
+
+/// Variant names of a unit-only serde enum, as written in the config
+fn variants<T: DeserializeOwned + Debug>() -> Vec<&'static str> {
+    let mut names: &'static [&'static str] = &[];
+    let _ = T::deserialize(VariantNames(&mut names));
+
+    // serde lists aliases too; they deserialize to a variant with another name
+    names
+        .iter()
+        .copied()
+        .filter(|name| {
+            let deserializer: StrDeserializer<'_, de::value::Error> = name.into_deserializer();
+            T::deserialize(deserializer).is_ok_and(|v| format!("{:?}", v) == *name)
+        })
+        .collect()
+}
+
+/// Deserializer that records the variant names an enum asks for, then fails
+struct VariantNames<'a>(&'a mut &'static [&'static str]);
+
+impl<'de> Deserializer<'de> for VariantNames<'_> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("expected an enum"))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.0 = variants;
+        Err(de::Error::custom("only variant names are read"))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
+
+/// Fuzzy matches for the word being typed, cycled through with Tab
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Completion {
+    /// Byte offset of the completed word in the input
+    start: usize,
+    /// Best match first
+    pub matches: Vec<String>,
+    /// Match last inserted into the input
+    pub selected: Option<usize>,
+}
+
+impl Completion {
+    /// Complete the command name, or the option being typed after `set`
+    pub fn new(input: &str, mode: Mode, commands: &HashMap<String, UserCommand>) -> Self {
+        let word = input.rsplit(char::is_whitespace).next().unwrap_or_default();
+        let start = input.len() - word.len();
+        let vocabulary = if start == 0 {
+            names(mode, commands)
+        } else if input.split_whitespace().next() == Some("set") {
+            setting_names()
+        } else {
+            Vec::new()
+        };
+        Self {
+            start,
+            matches: rank(word, vocabulary),
+            selected: None,
+        }
+    }
+
+    /// Replace the word with the next match, or the previous one when going backwards
+    pub fn cycle(&mut self, input: &mut String, forward: bool) {
+        let count = self.matches.len();
+        if count == 0 {
+            return;
+        }
+        let next = match (self.selected, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(i), true) => (i + 1) % count,
+            (Some(i), false) => (i + count - 1) % count,
+        };
+        input.truncate(self.start);
+        input.push_str(&self.matches[next]);
+        self.selected = Some(next);
+    }
+}
+
+/// Candidates containing the characters of `pattern` in order, best first
+fn rank(pattern: &str, candidates: Vec<String>) -> Vec<String> {
+    let mut scored: Vec<(i32, String)> = candidates
+        .into_iter()
+        .filter_map(|c| Some((fuzzy_score(pattern, &c)?, c)))
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    scored.dedup_by(|a, b| a.1 == b.1);
+    scored.into_iter().map(|(_, c)| c).collect()
+}
+
+/// Score `candidate` when it contains `pattern` as a subsequence, ignoring case.
+/// Matches at the start, at word boundaries and right after another match score higher,
+/// and shorter candidates win ties.
+fn fuzzy_score(pattern: &str, candidate: &str) -> Option<i32> {
+    let mut score = -(candidate.chars().count() as i32);
+    let mut chars = candidate.chars();
+    let mut previous: Option<char> = None;
+    let mut previous_matched = false;
+
+    for p in pattern.chars() {
+        loop {
+            let c = chars.next()?;
+            let word_start = match previous {
+                None => true,
+                Some(prev) => (c.is_uppercase() && !prev.is_uppercase()) || !prev.is_alphanumeric(),
+            };
+            let matched = c.to_lowercase().eq(p.to_lowercase());
+            if matched {
+                score += 1;
+                if previous.is_none() {
+                    score += 8;
+                } else if word_start {
+                    score += 6;
+                }
+                if previous_matched {
+                    score += 4;
+                }
+            }
+            previous = Some(c);
+            previous_matched = matched;
+            if matched {
+                break;
+            }
+        }
+    }
+    Some(score)
+}
+
+/// Command lines run this session, browsed with Up and Down
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    entries: Vec<String>,
+    /// Entry shown while browsing
+    position: Option<usize>,
+    /// What was typed before browsing, restored after the newest entry
+    draft: String,
+}
+
+impl History {
+    const LIMIT: usize = 100;
+
+    pub fn record(&mut self, line: &str) {
+        self.position = None;
+        let line = line.trim();
+        if line.is_empty() || self.entries.last().is_some_and(|last| last == line) {
+            return;
+        }
+        self.entries.push(line.to_string());
+        if self.entries.len() > Self::LIMIT {
+            self.entries.remove(0);
+        }
+    }
+
+    /// Stop browsing, so the next `older` starts from the newest entry
+    pub fn reset(&mut self) {
+        self.position = None;
+    }
+
+    pub fn older(&mut self, input: &str) -> Option<&str> {
+        let position = match self.position {
+            None if self.entries.is_empty() => return None,
+            None => {
+                self.draft = input.to_string();
+                self.entries.len() - 1
+            }
+            Some(0) => return None,
+            Some(position) => position - 1,
+        };
+        self.position = Some(position);
+        Some(&self.entries[position])
+    }
+
+    pub fn newer(&mut self) -> Option<&str> {
+        let position = self.position?;
+        if position + 1 < self.entries.len() {
+            self.position = Some(position + 1);
+            Some(&self.entries[position + 1])
+        } else {
+            self.position = None;
+            Some(&self.draft)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_blame(input: &str) -> Result<Command, String> {
+        let commands = HashMap::from([(
+            "fixup".to_string(),
+            toml::from_str::<UserCommand>("command = \"git commit --fixup=%(commit)\"").unwrap(),
+        )]);
+        parse(input, Mode::Blame, &commands)
+    }
+
+    #[test]
+    fn parses_actions_and_builtins() {
+        assert_eq!(
+            parse_blame("cursortop"),
+            Ok(Command::Action(Action::Blame(BlameAction::CursorTop)))
+        );
+        assert_eq!(
+            parse_blame("Quit"),
+            Ok(Command::Action(Action::Global(GlobalAction::Quit)))
+        );
+        assert_eq!(
+            parse_blame("fixup"),
+            Ok(Command::Action(Action::Command("fixup".to_string())))
+        );
+        assert_eq!(parse_blame(" goto 120 "), Ok(Command::Goto(120)));
+        assert_eq!(
+            parse_blame("rev v1.0"),
+            Ok(Command::Rev("v1.0".to_string()))
+        );
+        assert_eq!(
+            parse_blame("set nowrap legend! coloring=heatmap"),
+            Ok(Command::Set(vec![
+                Setting::Flag(Flag::Wrap, Some(false)),
+                Setting::Flag(Flag::Legend, None),
+                Setting::Coloring(Coloring::Heatmap),
+            ]))
+        );
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!(
+            parse_blame("ScrollDown"),
+            Err("Unknown command: ScrollDown".to_string())
+        );
+        assert_eq!(
+            parse_blame("goto x"),
+            Err("Not a line number: x".to_string())
+        );
+        assert_eq!(parse_blame("rev"), Err("Usage: rev <revision>".to_string()));
+        assert_eq!(
+            parse_blame("ShowDiff now"),
+            Err("ShowDiff takes no arguments".to_string())
+        );
+        assert_eq!(
+            parse_blame("set blocks=wide"),
+            Err("Unknown blocks: wide (expected Off, Separator, Alternate)".to_string())
+        );
+    }
+
+    #[test]
+    fn ranks_fuzzy_matches() {
+        let names = names(Mode::Blame, &HashMap::new());
+        assert_eq!(rank("ct", names.clone())[0], "CursorTop");
+        assert_eq!(rank("ysh", names.clone())[0], "YankShortHash");
+        assert_eq!(rank("SD", names.clone())[0], "ShowDiff");
+        assert!(rank("zzz", names).is_empty());
+
+        // Aliases kept for old configs are not offered
+        let names = super::names(Mode::Diff, &HashMap::new());
+        assert!(names.contains(&"OpenInBrowser".to_string()));
+        assert!(!names.contains(&"OpenInGitHub".to_string()));
+    }
+
+    #[test]
+    fn cycles_completions() {
+        let mut input = "set colo".to_string();
+        let mut completion = Completion::new(&input, Mode::Blame, &HashMap::new());
+        assert_eq!(completion.matches.len(), 3);
+        completion.cycle(&mut input, true);
+        assert_eq!(input, "set coloring=Age");
+        completion.cycle(&mut input, true);
+        assert_eq!(input, "set coloring=Author");
+        completion.cycle(&mut input, false);
+        completion.cycle(&mut input, false);
+        assert_eq!(input, "set coloring=Heatmap");
+    }
+
+    #[test]
+    fn browses_history() {
+        let mut history = History::default();
+        history.record("goto 10");
+        history.record("set wrap");
+        history.record("set wrap");
+        assert_eq!(history.older("go"), Some("set wrap"));
+        assert_eq!(history.older("ignored"), Some("goto 10"));
+        assert_eq!(history.older("ignored"), None);
+        assert_eq!(history.newer(), Some("set wrap"));
+        assert_eq!(history.newer(), Some("go"));
+        assert_eq!(history.newer(), None);
+    }
+}
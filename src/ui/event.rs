@@ -1,11 +1,14 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use crossterm::event::{self, Event};
 
-use crate::config::{KeyBinding, KeymapConfig};
+use crate::config::{KeyBinding, KeyCode, KeymapConfig, key_binding_to_string};
 use crate::ui::action::Action;
-use crate::ui::mode::{BlameModeHandler, DiffModeHandler, HelpModeHandler, Mode, ModeHandler};
+use crate::ui::mode::{
+    BlameModeHandler, DiffModeHandler, HelpModeHandler, IssuesModeHandler, KeyMatch,
+    MarksModeHandler, Mode, ModeHandler, PromptModeHandler, SessionsModeHandler, StatsModeHandler,
+};
 
 /// Event handler for terminal input
 pub struct EventHandler {
@@ -27,13 +30,190 @@ impl EventHandler {
     }
 }
 
-/// Convert crossterm key event to Action based on current mode
-pub fn key_to_action(key: event::KeyEvent, mode: &Mode, keymap: &KeymapConfig) -> Action {
-    let binding = KeyBinding::from(key);
-
+/// Match keys against the keymap of the current mode
+pub fn match_keys(keys: &[KeyBinding], mode: Mode, keymap: &KeymapConfig) -> KeyMatch {
     match mode {
-        Mode::Blame => BlameModeHandler.handle_key(binding, keymap),
-        Mode::Diff => DiffModeHandler.handle_key(binding, keymap),
-        Mode::Help => HelpModeHandler.handle_key(binding, keymap),
+        Mode::Blame => BlameModeHandler.handle_keys(keys, keymap),
+        Mode::Diff => DiffModeHandler.handle_keys(keys, keymap),
+        Mode::Help => HelpModeHandler.handle_keys(keys, keymap),
+        Mode::Stats => StatsModeHandler.handle_keys(keys, keymap),
+        Mode::Sessions => SessionsModeHandler.handle_keys(keys, keymap),
+        Mode::Marks => MarksModeHandler.handle_keys(keys, keymap),
+        Mode::Issues => IssuesModeHandler.handle_keys(keys, keymap),
+        Mode::Prompt => PromptModeHandler.handle_keys(keys, keymap),
+    }
+}
+
+/// An action with the count typed before it, as in `5j`
+pub type CountedAction = (Action, Option<usize>);
+
+/// Keys of an unfinished multi-key binding and the count typed before them
+#[derive(Debug, Default)]
+pub struct PendingKeys {
+    keys: Vec<KeyBinding>,
+    count: Option<usize>,
+    /// What the keys typed so far run if the longer binding is not finished
+    fallback: Option<Action>,
+    /// When the last key of the unfinished binding was pressed
+    since: Option<Instant>,
+}
+
+impl PendingKeys {
+    const MAX_COUNT: usize = 9999;
+
+    /// Add a key press, returning the actions it completes
+    pub fn push(
+        &mut self,
+        key: KeyBinding,
+        mode: Mode,
+        keymap: &KeymapConfig,
+    ) -> Vec<CountedAction> {
+        // Text input takes every key as it comes
+        if mode == Mode::Prompt {
+            self.clear();
+            return match match_keys(&[key], mode, keymap) {
+                KeyMatch::Action(action) => vec![(action, None)],
+                _ => Vec::new(),
+            };
+        }
+
+        // Escape abandons a count or an unfinished binding
+        if key.key == KeyCode::Escape && self.hint().is_some() {
+            self.clear();
+            return Vec::new();
+        }
+
+        // Digits build a count, unless a binding starts with them; a count can't start with 0
+        if let KeyCode::Char(c @ '0'..='9') = key.key
+            && self.keys.is_empty()
+            && key.modifiers == Default::default()
+            && (self.count.is_some() || c != '0')
+            && match_keys(std::slice::from_ref(&key), mode, keymap) == KeyMatch::None
+        {
+            let digit = c as usize - '0' as usize;
+            let count = self.count.unwrap_or(0) * 10 + digit;
+            self.count = Some(count.min(Self::MAX_COUNT));
+            return Vec::new();
+        }
+
+        self.keys.push(key.clone());
+        match match_keys(&self.keys, mode, keymap) {
+            KeyMatch::Action(action) => {
+                let count = self.count;
+                self.clear();
+                vec![(action, count)]
+            }
+            KeyMatch::Pending(fallback) => {
+                self.fallback = fallback;
+                self.since = Some(Instant::now());
+                Vec::new()
+            }
+            KeyMatch::None => {
+                // Run what the earlier keys mean alone, then start over from this key
+                let restart = self.keys.len() > 1;
+                let count = self.count;
+                let fallback = self.fallback.take();
+                self.clear();
+                let mut actions: Vec<CountedAction> =
+                    fallback.map(|action| (action, count)).into_iter().collect();
+                if restart {
+                    actions.extend(self.push(key, mode, keymap));
+                }
+                actions
+            }
+        }
+    }
+
+    /// Give up on a binding left unfinished for `timeout`, running what its keys mean alone
+    pub fn expire(&mut self, timeout: Duration) -> Option<CountedAction> {
+        if self.since.is_none_or(|since| since.elapsed() < timeout) {
+            return None;
+        }
+        let count = self.count;
+        let fallback = self.fallback.take();
+        self.clear();
+        fallback.map(|action| (action, count))
+    }
+
+    /// The count and keys typed so far, e.g. `5g`
+    pub fn hint(&self) -> Option<String> {
+        if self.keys.is_empty() && self.count.is_none() {
+            return None;
+        }
+        let keys: Vec<String> = self.keys.iter().map(key_binding_to_string).collect();
+        Some(format!(
+            "{}{}",
+            self.count.map(|c| c.to_string()).unwrap_or_default(),
+            keys.join(" ")
+        ))
+    }
+
+    fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::KeySequence;
+    use crate::ui::action::{BlameAction, GlobalAction};
+
+    fn press(pending: &mut PendingKeys, keys: &str, keymap: &KeymapConfig) -> Vec<CountedAction> {
+        keys.chars()
+            .flat_map(|c| pending.push(KeyBinding::new(KeyCode::Char(c)), Mode::Blame, keymap))
+            .collect()
+    }
+
+    #[test]
+    fn matches_sequences_and_counts() {
+        let keymap = KeymapConfig::with_defaults();
+        let mut pending = PendingKeys::default();
+
+        assert_eq!(press(&mut pending, "g", &keymap), vec![]);
+        assert_eq!(pending.hint().as_deref(), Some("g"));
+        assert_eq!(
+            press(&mut pending, "g", &keymap),
+            vec![(Action::Blame(BlameAction::CursorTop), None)]
+        );
+
+        assert_eq!(press(&mut pending, "12", &keymap), vec![]);
+        assert_eq!(pending.hint().as_deref(), Some("12"));
+        assert_eq!(
+            press(&mut pending, "j", &keymap),
+            vec![(Action::Blame(BlameAction::CursorDown), Some(12))]
+        );
+
+        // An unfinished sequence is dropped and the next key starts over
+        assert_eq!(
+            press(&mut pending, "]k", &keymap),
+            vec![(Action::Blame(BlameAction::CursorUp), None)]
+        );
+        assert_eq!(pending.hint(), None);
+    }
+
+    #[test]
+    fn runs_the_shorter_binding_on_timeout() {
+        let mut keymap = KeymapConfig::with_defaults();
+        keymap
+            .global
+            .insert(KeySequence::chars("g"), GlobalAction::ShowHelp);
+        let mut pending = PendingKeys::default();
+
+        assert_eq!(press(&mut pending, "g", &keymap), vec![]);
+        assert_eq!(pending.expire(Duration::from_secs(60)), None);
+        assert_eq!(
+            pending.expire(Duration::ZERO),
+            Some((Action::Global(GlobalAction::ShowHelp), None))
+        );
+
+        // Another key runs it straight away and is matched on its own
+        assert_eq!(
+            press(&mut pending, "gk", &keymap),
+            vec![
+                (Action::Global(GlobalAction::ShowHelp), None),
+                (Action::Blame(BlameAction::CursorUp), None)
+            ]
+        );
     }
 }
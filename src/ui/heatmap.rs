@@ -0,0 +1,212 @@
+use ratatui::style::{Color, Style};
+use ratatui::text::Span;
+
+use crate::config::theme::ColorValue;
+use crate::config::{HeatmapConfig, HeatmapScale};
+use crate::domain::BlameEntry;
+
+const DAY: i64 = 86400;
+
+/// Number of gradient cells in the status bar legend
+const LEGEND_CELLS: usize = 8;
+
+/// Maps line timestamps onto the configured colour gradient
+pub struct Heatmap<'a> {
+    config: &'a HeatmapConfig,
+    /// Age in seconds shown in the hottest colour
+    newest_age: i64,
+    /// Age in seconds shown in the coldest colour
+    oldest_age: i64,
+    now: i64,
+}
+
+impl<'a> Heatmap<'a> {
+    pub fn new(config: &'a HeatmapConfig, entries: &[BlameEntry], now: i64) -> Self {
+        let (newest_age, oldest_age) = match config.scale {
+            HeatmapScale::Absolute => (
+                config.newest_days as i64 * DAY,
+                config.oldest_days as i64 * DAY,
+            ),
+            HeatmapScale::Relative => entries.iter().fold((i64::MAX, i64::MIN), |(new, old), e| {
+                let age = now - e.timestamp;
+                (new.min(age), old.max(age))
+            }),
+        };
+
+        Self {
+            config,
+            newest_age,
+            oldest_age,
+            now,
+        }
+    }
+
+    /// How recent `timestamp` is on this scale, from 0.0 (oldest) to 1.0 (newest)
+    pub fn heat(&self, timestamp: i64) -> f64 {
+        let age = self.now - timestamp;
+        if age <= self.newest_age {
+            return 1.0;
+        }
+        if age >= self.oldest_age {
+            return 0.0;
+        }
+
+        match self.config.scale {
+            // Log scale, so a week and a month stay as distinguishable as one and two years
+            HeatmapScale::Absolute => {
+                let newest = (self.newest_age.max(60) as f64).ln();
+                let oldest = (self.oldest_age.max(60) as f64).ln();
+                if oldest <= newest {
+                    return 1.0;
+                }
+                1.0 - ((age as f64).ln() - newest) / (oldest - newest)
+            }
+            HeatmapScale::Relative => {
+                1.0 - (age - self.newest_age) as f64 / (self.oldest_age - self.newest_age) as f64
+            }
+        }
+        .clamp(0.0, 1.0)
+    }
+
+    pub fn color(&self, timestamp: i64) -> Color {
+        gradient(&self.config.gradient, 1.0 - self.heat(timestamp))
+    }
+
+    /// Legend such as `7d ████████ 2y`, with labels in `style`
+    pub fn legend(&self, style: Style) -> Vec<Span<'static>> {
+        if self.newest_age > self.oldest_age {
+            return vec![];
+        }
+
+        let mut spans = vec![Span::styled(
+            format!(" {} ", short_age(self.newest_age)),
+            style,
+        )];
+        for cell in 0..LEGEND_CELLS {
+            let position = cell as f64 / (LEGEND_CELLS - 1) as f64;
+            spans.push(Span::styled(
+                "█",
+                style.fg(gradient(&self.config.gradient, position)),
+            ));
+        }
+        spans.push(Span::styled(
+            format!(" {} ", short_age(self.oldest_age)),
+            style,
+        ));
+        spans
+    }
+}
+
+/// Colour at `position` (0.0 = first stop, 1.0 = last stop).
+///
+/// Adjacent truecolor stops are interpolated; other colours can't be blended,
+/// so the nearest stop is used instead.
+fn gradient(stops: &[ColorValue], position: f64) -> Color {
+    let Some(last) = stops.len().checked_sub(1) else {
+        return Color::Reset;
+    };
+
+    let scaled = position.clamp(0.0, 1.0) * last as f64;
+    let index = (scaled.floor() as usize).min(last);
+    let next = (index + 1).min(last);
+    let fraction = scaled - index as f64;
+
+    match (stops[index].0, stops[next].0) {
+        (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+            let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * fraction).round() as u8;
+            Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+        }
+        (near, _) if fraction < 0.5 => near,
+        (_, far) => far,
+    }
+}
+
+/// Compact age label, e.g. `3h`, `7d`, `2w`, `5mo`, `2y`
+fn short_age(secs: i64) -> String {
+    let secs = secs.max(0);
+    match secs {
+        s if s < DAY => format!("{}h", s / 3600),
+        s if s < 14 * DAY => format!("{}d", s / DAY),
+        s if s < 60 * DAY => format!("{}w", s / (7 * DAY)),
+        s if s < 365 * DAY => format!("{}mo", s / (30 * DAY)),
+        s => format!("{}y", s / (365 * DAY)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn entry(timestamp: i64) -> BlameEntry {
+        BlameEntry::fixture("abc1234", "Alice").with_time(timestamp, 0)
+    }
+
+    #[test]
+    fn absolute_scale_saturates_outside_the_window() {
+        let config = HeatmapConfig::default();
+        let heatmap = Heatmap::new(&config, &[], NOW);
+        assert_eq!(heatmap.heat(NOW - DAY), 1.0);
+        assert_eq!(heatmap.heat(NOW - 1000 * DAY), 0.0);
+
+        let month = heatmap.heat(NOW - 30 * DAY);
+        let year = heatmap.heat(NOW - 365 * DAY);
+        assert!(0.0 < year && year < month && month < 1.0);
+    }
+
+    #[test]
+    fn relative_scale_spans_the_frame() {
+        let config = HeatmapConfig {
+            scale: HeatmapScale::Relative,
+            ..HeatmapConfig::default()
+        };
+        let entries = [entry(NOW - 100 * DAY), entry(NOW - 50 * DAY), entry(NOW)];
+        let heatmap = Heatmap::new(&config, &entries, NOW);
+        assert_eq!(heatmap.heat(NOW), 1.0);
+        assert_eq!(heatmap.heat(NOW - 100 * DAY), 0.0);
+        assert_eq!(heatmap.heat(NOW - 50 * DAY), 0.5);
+    }
+
+    #[test]
+    fn gradient_interpolates_truecolor_stops() {
+        let stops = [
+            ColorValue(Color::Rgb(0, 0, 0)),
+            ColorValue(Color::Rgb(200, 100, 50)),
+        ];
+        assert_eq!(gradient(&stops, 0.0), Color::Rgb(0, 0, 0));
+        assert_eq!(gradient(&stops, 0.5), Color::Rgb(100, 50, 25));
+        assert_eq!(gradient(&stops, 1.0), Color::Rgb(200, 100, 50));
+    }
+
+    #[test]
+    fn gradient_snaps_to_named_stops() {
+        let stops = [ColorValue(Color::Red), ColorValue(Color::Blue)];
+        assert_eq!(gradient(&stops, 0.4), Color::Red);
+        assert_eq!(gradient(&stops, 0.6), Color::Blue);
+        assert_eq!(gradient(&[], 0.5), Color::Reset);
+    }
+
+    #[test]
+    fn legend_labels_the_scale() {
+        let config = HeatmapConfig::default();
+        let legend = Heatmap::new(&config, &[], NOW).legend(Style::default());
+        assert_eq!(legend.first().unwrap().content, " 7d ");
+        assert_eq!(legend.last().unwrap().content, " 2y ");
+        assert_eq!(legend.len(), LEGEND_CELLS + 2);
+    }
+
+    proptest! {
+        #[test]
+        fn heat_stays_in_range(timestamp in any::<i32>(), newest in 0u32..100, oldest in 0u32..2000) {
+            let config = HeatmapConfig {
+                newest_days: newest,
+                oldest_days: oldest,
+                ..HeatmapConfig::default()
+            };
+            let heat = Heatmap::new(&config, &[], NOW).heat(timestamp as i64);
+            prop_assert!((0.0..=1.0).contains(&heat));
+        }
+    }
+}
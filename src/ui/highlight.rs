@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{FontStyle, Theme as SyntaxTheme, ThemeSet};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+
+use crate::domain::{BlameFrame, CommitHash};
+
+/// Files longer than this are left unhighlighted to keep drill-downs responsive
+const MAX_HIGHLIGHT_LINES: usize = 20_000;
+
+/// Highlights blamed content, caching the result per frame
+pub struct Highlighter {
+    syntaxes: SyntaxSet,
+    theme: SyntaxTheme,
+    /// `None` records files without a known grammar, so they aren't retried
+    cache: HashMap<(CommitHash, PathBuf), Option<Vec<Line<'static>>>>,
+}
+
+impl Highlighter {
+    /// Create a highlighter using one of syntect's built-in themes
+    pub fn new(theme_name: &str) -> Result<Self, String> {
+        let mut themes = ThemeSet::load_defaults().themes;
+        let theme = themes.remove(theme_name).ok_or_else(|| {
+            let mut names: Vec<&String> = themes.keys().collect();
+            names.sort();
+            let names: Vec<&str> = names.iter().map(|n| n.as_str()).collect();
+            format!(
+                "Unknown syntax theme: {} (available: {})",
+                theme_name,
+                names.join(", ")
+            )
+        })?;
+
+        Ok(Self {
+            syntaxes: SyntaxSet::load_defaults_newlines(),
+            theme,
+            cache: HashMap::new(),
+        })
+    }
+
+    /// Highlight a frame unless it is already cached
+    pub fn highlight(&mut self, frame: &BlameFrame) {
+        let key = (frame.commit_hash.clone(), frame.file_path.clone());
+        if self.cache.contains_key(&key) {
+            return;
+        }
+        let lines = self.highlight_lines(frame);
+        self.cache.insert(key, lines);
+    }
+
+    /// Highlighted content for a frame, one line per blame entry
+    pub fn cached(&self, frame: &BlameFrame) -> Option<&[Line<'static>]> {
+        self.cache
+            .get(&(frame.commit_hash.clone(), frame.file_path.clone()))?
+            .as_deref()
+    }
+
+    fn highlight_lines(&self, frame: &BlameFrame) -> Option<Vec<Line<'static>>> {
+        if frame.entries.len() > MAX_HIGHLIGHT_LINES {
+            return None;
+        }
+        let first_line = frame.entries.first().map(|e| e.content.as_str());
+        let syntax = self.syntax_for(&frame.file_path, first_line.unwrap_or_default())?;
+
+        // Feed the whole file in order so multi-line strings and comments carry over
+        let mut highlighter = HighlightLines::new(syntax, &self.theme);
+        let mut lines = Vec::with_capacity(frame.entries.len());
+        for entry in &frame.entries {
+            let content = format!("{}\n", entry.content);
+            let regions = highlighter.highlight_line(&content, &self.syntaxes).ok()?;
+            let spans: Vec<Span<'static>> = regions
+                .into_iter()
+                .map(|(style, text)| {
+                    Span::styled(text.trim_end_matches('\n').to_string(), to_style(style))
+                })
+                .filter(|span| !span.content.is_empty())
+                .collect();
+            lines.push(Line::from(spans));
+        }
+        Some(lines)
+    }
+
+    /// Grammar from the file name or extension, falling back to a shebang or modeline
+    fn syntax_for(&self, path: &Path, first_line: &str) -> Option<&SyntaxReference> {
+        let name = path.file_name().and_then(|n| n.to_str());
+        let extension = path.extension().and_then(|e| e.to_str());
+        name.and_then(|n| self.syntaxes.find_syntax_by_extension(n))
+            .or_else(|| extension.and_then(|e| self.syntaxes.find_syntax_by_extension(e)))
+            .or_else(|| self.syntaxes.find_syntax_by_first_line(first_line))
+            .filter(|syntax| syntax.name != "Plain Text")
+    }
+}
+
+/// Foreground and font style only; the blame view owns backgrounds
+fn to_style(style: syntect::highlighting::Style) -> Style {
+    let fg = style.foreground;
+    let mut result = Style::default().fg(Color::Rgb(fg.r, fg.g, fg.b));
+    let modifiers = [
+        (FontStyle::BOLD, Modifier::BOLD),
+        (FontStyle::ITALIC, Modifier::ITALIC),
+        (FontStyle::UNDERLINE, Modifier::UNDERLINED),
+    ];
+    for (font_style, modifier) in modifiers {
+        if style.font_style.contains(font_style) {
+            result = result.add_modifier(modifier);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::BlameEntry;
+
+    fn frame(path: &str, lines: &[&str]) -> BlameFrame {
+        let entries = lines
+            .iter()
+            .map(|line| BlameEntry::fixture("abc1234", "Alice").with_content(line))
+            .collect();
+        BlameFrame::fixture("abc1234", entries).with_path(path)
+    }
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn highlights_by_extension_and_keeps_text() {
+        let mut highlighter = Highlighter::new("base16-ocean.dark").unwrap();
+        let frame = frame("src/main.rs", &["fn main() {", "    let x = 1;", "}"]);
+        highlighter.highlight(&frame);
+
+        let lines = highlighter.cached(&frame).unwrap();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(text(&lines[1]), "    let x = 1;");
+        assert!(lines[0].spans.len() > 1);
+    }
+
+    #[test]
+    fn carries_state_across_lines() {
+        let mut highlighter = Highlighter::new("base16-ocean.dark").unwrap();
+        let frame = frame(
+            "a.rs",
+            &["/* start", "let inside = 1;", "*/", "let outside = 1;"],
+        );
+        highlighter.highlight(&frame);
+
+        let lines = highlighter.cached(&frame).unwrap();
+        // The commented line is a single comment-coloured span
+        assert_eq!(lines[1].spans.len(), 1);
+        assert!(lines[3].spans.len() > 1);
+    }
+
+    #[test]
+    fn falls_back_to_shebang() {
+        let mut highlighter = Highlighter::new("base16-ocean.dark").unwrap();
+        let frame = frame("bin/run", &["#!/usr/bin/env python3", "print('hi')"]);
+        highlighter.highlight(&frame);
+        assert!(highlighter.cached(&frame).is_some());
+
+        let plain = self::frame("notes", &["just text"]);
+        highlighter.highlight(&plain);
+        assert!(highlighter.cached(&plain).is_none());
+    }
+
+    #[test]
+    fn unknown_theme_is_an_error() {
+        assert!(Highlighter::new("no-such-theme").is_err());
+    }
+}
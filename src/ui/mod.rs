@@ -1,6 +1,11 @@
 pub mod action;
 pub mod app;
+pub mod command_line;
 pub mod event;
+pub mod heatmap;
+pub mod highlight;
 pub mod layout;
 pub mod mode;
+pub mod prompt;
+pub mod theme;
 pub mod widget;
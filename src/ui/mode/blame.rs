@@ -1,22 +1,17 @@
 use crate::config::{KeyBinding, KeymapConfig};
 use crate::ui::action::Action;
 
-use super::ModeHandler;
+use super::{KeyMatch, ModeHandler};
 
 pub struct BlameModeHandler;
 
 impl ModeHandler for BlameModeHandler {
-    fn handle_key(&self, key: KeyBinding, keymap: &KeymapConfig) -> Action {
-        // Check blame-specific keymap first
-        if let Some(action) = keymap.blame.get(&key) {
-            return Action::Blame(*action);
-        }
-
-        // Then check global keymap
-        if let Some(action) = keymap.global.get(&key) {
-            return Action::Global(*action);
-        }
-
-        Action::None
+    fn handle_keys(&self, keys: &[KeyBinding], keymap: &KeymapConfig) -> KeyMatch {
+        // User commands are bound explicitly, so they take precedence
+        KeyMatch::lookup(&keymap.commands.blame, keys, Action::Command)
+            // Then the blame-specific keymap
+            .or(KeyMatch::lookup(&keymap.blame, keys, Action::Blame))
+            // Then check global keymap
+            .or(KeyMatch::lookup(&keymap.global, keys, Action::Global))
     }
 }
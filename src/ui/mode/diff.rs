@@ -1,22 +1,17 @@
 use crate::config::{KeyBinding, KeymapConfig};
 use crate::ui::action::Action;
 
-use super::ModeHandler;
+use super::{KeyMatch, ModeHandler};
 
 pub struct DiffModeHandler;
 
 impl ModeHandler for DiffModeHandler {
-    fn handle_key(&self, key: KeyBinding, keymap: &KeymapConfig) -> Action {
-        // Check diff-specific keymap first
-        if let Some(action) = keymap.diff.get(&key) {
-            return Action::Diff(*action);
-        }
-
-        // Then check global keymap
-        if let Some(action) = keymap.global.get(&key) {
-            return Action::Global(*action);
-        }
-
-        Action::None
+    fn handle_keys(&self, keys: &[KeyBinding], keymap: &KeymapConfig) -> KeyMatch {
+        // User commands are bound explicitly, so they take precedence
+        KeyMatch::lookup(&keymap.commands.diff, keys, Action::Command)
+            // Then the diff-specific keymap
+            .or(KeyMatch::lookup(&keymap.diff, keys, Action::Diff))
+            // Then check global keymap
+            .or(KeyMatch::lookup(&keymap.global, keys, Action::Global))
     }
 }
@@ -1,22 +1,15 @@
 use crate::config::{KeyBinding, KeymapConfig};
 use crate::ui::action::Action;
 
-use super::ModeHandler;
+use super::{KeyMatch, ModeHandler};
 
 pub struct HelpModeHandler;
 
 impl ModeHandler for HelpModeHandler {
-    fn handle_key(&self, key: KeyBinding, keymap: &KeymapConfig) -> Action {
+    fn handle_keys(&self, keys: &[KeyBinding], keymap: &KeymapConfig) -> KeyMatch {
         // Check help-specific keymap first
-        if let Some(action) = keymap.help.get(&key) {
-            return Action::Help(*action);
-        }
-
-        // Then check global keymap
-        if let Some(action) = keymap.global.get(&key) {
-            return Action::Global(*action);
-        }
-
-        Action::None
+        KeyMatch::lookup(&keymap.help, keys, Action::Help)
+            // Then check global keymap
+            .or(KeyMatch::lookup(&keymap.global, keys, Action::Global))
     }
 }
@@ -0,0 +1,15 @@
+use crate::config::{KeyBinding, KeymapConfig};
+use crate::ui::action::Action;
+
+use super::{KeyMatch, ModeHandler};
+
+pub struct IssuesModeHandler;
+
+impl ModeHandler for IssuesModeHandler {
+    fn handle_keys(&self, keys: &[KeyBinding], keymap: &KeymapConfig) -> KeyMatch {
+        // Check issues-specific keymap first
+        KeyMatch::lookup(&keymap.issues, keys, Action::Issues)
+            // Then check global keymap
+            .or(KeyMatch::lookup(&keymap.global, keys, Action::Global))
+    }
+}
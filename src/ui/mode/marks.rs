@@ -0,0 +1,15 @@
+use crate::config::{KeyBinding, KeymapConfig};
+use crate::ui::action::Action;
+
+use super::{KeyMatch, ModeHandler};
+
+pub struct MarksModeHandler;
+
+impl ModeHandler for MarksModeHandler {
+    fn handle_keys(&self, keys: &[KeyBinding], keymap: &KeymapConfig) -> KeyMatch {
+        // Check marks-specific keymap first
+        KeyMatch::lookup(&keymap.marks, keys, Action::Marks)
+            // Then check global keymap
+            .or(KeyMatch::lookup(&keymap.global, keys, Action::Global))
+    }
+}
@@ -1,12 +1,24 @@
 mod blame;
 mod diff;
 mod help;
+mod issues;
+mod marks;
+mod prompt;
+mod sessions;
+mod stats;
 
 pub use blame::BlameModeHandler;
 pub use diff::DiffModeHandler;
 pub use help::HelpModeHandler;
+pub use issues::IssuesModeHandler;
+pub use marks::MarksModeHandler;
+pub use prompt::PromptModeHandler;
+pub use sessions::SessionsModeHandler;
+pub use stats::StatsModeHandler;
 
-use crate::config::{KeyBinding, KeymapConfig};
+use std::collections::HashMap;
+
+use crate::config::{KeyBinding, KeySequence, KeymapConfig};
 use crate::ui::action::Action;
 
 /// Application mode
@@ -16,6 +28,12 @@ pub enum Mode {
     Blame,
     Diff,
     Help,
+    Stats,
+    Sessions,
+    Marks,
+    Issues,
+    /// Text input in the status bar
+    Prompt,
 }
 
 impl Mode {
@@ -24,11 +42,61 @@ impl Mode {
             Mode::Blame => "BLAME",
             Mode::Diff => "DIFF",
             Mode::Help => "HELP",
+            Mode::Stats => "STATS",
+            Mode::Sessions => "SESSIONS",
+            Mode::Marks => "MARKS",
+            Mode::Issues => "ISSUES",
+            Mode::Prompt => "INPUT",
+        }
+    }
+}
+
+/// What the keys typed so far mean in a mode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyMatch {
+    /// The keys are a whole binding
+    Action(Action),
+    /// The keys start a longer binding; the action is what they run alone, if anything
+    Pending(Option<Action>),
+    None,
+}
+
+impl KeyMatch {
+    /// Look the keys up in one section of the keymap
+    pub fn lookup<T: Clone>(
+        bindings: &HashMap<KeySequence, T>,
+        keys: &[KeyBinding],
+        action: impl Fn(T) -> Action,
+    ) -> Self {
+        let exact = bindings
+            .get(&KeySequence(keys.to_vec()))
+            .map(|a| action(a.clone()));
+        if bindings.keys().any(|sequence| sequence.extends(keys)) {
+            KeyMatch::Pending(exact)
+        } else {
+            exact.map_or(KeyMatch::None, KeyMatch::Action)
+        }
+    }
+
+    /// Fall back to a section of lower precedence; a longer binding in either keeps the keys pending
+    pub fn or(self, lower: KeyMatch) -> Self {
+        match (self, lower) {
+            (KeyMatch::Action(action), KeyMatch::Pending(_)) => KeyMatch::Pending(Some(action)),
+            (KeyMatch::Action(action), _) => KeyMatch::Action(action),
+            (KeyMatch::Pending(action), KeyMatch::Action(lower)) => {
+                KeyMatch::Pending(action.or(Some(lower)))
+            }
+            (KeyMatch::Pending(action), KeyMatch::Pending(lower)) => {
+                KeyMatch::Pending(action.or(lower))
+            }
+            (KeyMatch::Pending(action), KeyMatch::None) => KeyMatch::Pending(action),
+            (KeyMatch::None, lower) => lower,
         }
     }
 }
 
 /// Trait for handling key events per mode
 pub trait ModeHandler {
-    fn handle_key(&self, key: KeyBinding, keymap: &KeymapConfig) -> Action;
+    /// Match the keys typed since the last binding finished
+    fn handle_keys(&self, keys: &[KeyBinding], keymap: &KeymapConfig) -> KeyMatch;
 }
@@ -0,0 +1,33 @@
+use crate::config::{KeyBinding, KeyCode, KeymapConfig};
+use crate::ui::action::{Action, PromptAction};
+
+use super::{KeyMatch, ModeHandler};
+
+pub struct PromptModeHandler;
+
+impl ModeHandler for PromptModeHandler {
+    fn handle_keys(&self, keys: &[KeyBinding], _keymap: &KeymapConfig) -> KeyMatch {
+        // Prompt keys are single keys; sequences and counts don't apply
+        let [key] = keys else {
+            return KeyMatch::None;
+        };
+        let action = match key.key {
+            KeyCode::Enter => PromptAction::Submit,
+            KeyCode::Escape => PromptAction::Cancel,
+            KeyCode::Backspace => PromptAction::Backspace,
+            KeyCode::Tab => PromptAction::Complete,
+            KeyCode::BackTab => PromptAction::CompletePrevious,
+            KeyCode::Up => PromptAction::HistoryPrevious,
+            KeyCode::Down => PromptAction::HistoryNext,
+            KeyCode::Char('p') if key.modifiers.ctrl => PromptAction::HistoryPrevious,
+            KeyCode::Char('n') if key.modifiers.ctrl => PromptAction::HistoryNext,
+            KeyCode::Char('w') if key.modifiers.ctrl => PromptAction::DeleteWord,
+            KeyCode::Char('c') if key.modifiers.ctrl => PromptAction::Cancel,
+            KeyCode::Char(c) if !key.modifiers.ctrl && !key.modifiers.alt => {
+                PromptAction::Insert(c)
+            }
+            _ => return KeyMatch::None,
+        };
+        KeyMatch::Action(Action::Prompt(action))
+    }
+}
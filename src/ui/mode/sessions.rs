@@ -0,0 +1,15 @@
+use crate::config::{KeyBinding, KeymapConfig};
+use crate::ui::action::Action;
+
+use super::{KeyMatch, ModeHandler};
+
+pub struct SessionsModeHandler;
+
+impl ModeHandler for SessionsModeHandler {
+    fn handle_keys(&self, keys: &[KeyBinding], keymap: &KeymapConfig) -> KeyMatch {
+        // Check sessions-specific keymap first
+        KeyMatch::lookup(&keymap.sessions, keys, Action::Sessions)
+            // Then check global keymap
+            .or(KeyMatch::lookup(&keymap.global, keys, Action::Global))
+    }
+}
@@ -0,0 +1,15 @@
+use crate::config::{KeyBinding, KeymapConfig};
+use crate::ui::action::Action;
+
+use super::{KeyMatch, ModeHandler};
+
+pub struct StatsModeHandler;
+
+impl ModeHandler for StatsModeHandler {
+    fn handle_keys(&self, keys: &[KeyBinding], keymap: &KeymapConfig) -> KeyMatch {
+        // Check stats-specific keymap first
+        KeyMatch::lookup(&keymap.stats, keys, Action::Stats)
+            // Then check global keymap
+            .or(KeyMatch::lookup(&keymap.global, keys, Action::Global))
+    }
+}
@@ -0,0 +1,105 @@
+/// What a submitted prompt is used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptKind {
+    /// File to write the trail report to
+    ExportTrail,
+    /// Name of the mark to set on the selected line
+    SetMark,
+    /// Name of the mark to jump to
+    JumpToMark,
+    /// Issue whose commits to highlight in the blame view
+    HighlightIssue,
+    /// Whether to edit a read-only copy of a file at an older revision
+    EditRevisionCopy,
+    /// `:` command line running actions and commands by name
+    Command,
+}
+
+impl PromptKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PromptKind::ExportTrail => "Export trail to:",
+            PromptKind::SetMark => "Set mark:",
+            PromptKind::JumpToMark => "Jump to mark:",
+            PromptKind::HighlightIssue => "Highlight issue:",
+            PromptKind::EditRevisionCopy => {
+                "Not the checked-out revision; open a read-only copy? (y/n)"
+            }
+            PromptKind::Command => ":",
+        }
+    }
+
+    /// Prompts that submit as soon as one character is typed
+    pub fn is_single_key(&self) -> bool {
+        matches!(
+            self,
+            PromptKind::SetMark | PromptKind::JumpToMark | PromptKind::EditRevisionCopy
+        )
+    }
+}
+
+/// Single-line text input shown in the status bar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Prompt {
+    pub kind: PromptKind,
+    pub input: String,
+    /// Why the last submission failed, shown after the input until it is edited
+    pub error: Option<String>,
+}
+
+impl Prompt {
+    pub fn new(kind: PromptKind, input: impl Into<String>) -> Self {
+        Self {
+            kind,
+            input: input.into(),
+            error: None,
+        }
+    }
+
+    pub fn with_error(mut self, error: impl Into<String>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    pub fn insert(&mut self, c: char) {
+        self.input.push(c);
+        self.error = None;
+    }
+
+    pub fn backspace(&mut self) {
+        self.input.pop();
+        self.error = None;
+    }
+
+    /// Replace the whole input, e.g. with a history entry
+    pub fn replace(&mut self, input: &str) {
+        self.input = input.to_string();
+        self.error = None;
+    }
+
+    /// Delete back to the previous whitespace or path separator
+    pub fn delete_word(&mut self) {
+        let trimmed = self.input.trim_end_matches([' ', '/']);
+        let start = trimmed.rfind([' ', '/']).map_or(0, |i| i + 1);
+        self.input.truncate(start);
+        self.error = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edits_input() {
+        let mut prompt = Prompt::new(PromptKind::ExportTrail, "notes/trail.m");
+        prompt.insert('d');
+        assert_eq!(prompt.input, "notes/trail.md");
+        prompt.delete_word();
+        assert_eq!(prompt.input, "notes/");
+        prompt.delete_word();
+        assert_eq!(prompt.input, "");
+        prompt.backspace();
+        assert_eq!(prompt.input, "");
+    }
+}
@@ -0,0 +1,569 @@
+use std::collections::{HashMap, HashSet};
+
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::config::{StyleConfig, ThemeConfig, ThemeName, ThemeStyles};
+use crate::domain::blame::AuthorLines;
+
+/// Resolved styles for every themed element
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    // Blame view
+    pub hash_newest: Style,
+    pub hash_new: Style,
+    pub hash_old: Style,
+    pub hash_oldest: Style,
+    pub author: Style,
+    pub author_email: Style,
+    pub date: Style,
+    pub age: Style,
+    pub line_number: Style,
+    pub original_path: Style,
+    pub subject: Style,
+    pub trail_index: Style,
+    pub content: Style,
+    /// Background of every other hunk in the alternating block style
+    pub hunk_alternate: Style,
+    /// Lines of the author or commit selected in the ownership overlay, or of the highlighted issue
+    pub owner_highlight: Style,
+
+    // Shared
+    pub selection: Style,
+    pub border: Style,
+    pub title: Style,
+
+    // Status bar
+    pub status_bar: Style,
+    pub status_mode: Style,
+    pub status_chain: Style,
+    pub status_message: Style,
+
+    // Diff view
+    pub diff_hash: Style,
+    pub diff_refs: Style,
+    /// Issue tracker references in the commit message
+    pub diff_issue: Style,
+    pub diff_label: Style,
+
+    // Help view
+    pub help_title: Style,
+    pub help_section: Style,
+
+    /// Colours assigned to authors in the author colouring
+    pub author_palette: &'static [Color],
+    /// Built-in syntect theme used to highlight blamed content
+    pub syntax_theme: &'static str,
+
+    /// Colours are disabled (`NO_COLOR`); only modifiers remain
+    pub no_color: bool,
+}
+
+/// Author colours readable on dark backgrounds
+const DARK_AUTHOR_PALETTE: &[Color] = &[
+    Color::Indexed(39),
+    Color::Indexed(208),
+    Color::Indexed(78),
+    Color::Indexed(170),
+    Color::Indexed(220),
+    Color::Indexed(45),
+    Color::Indexed(203),
+    Color::Indexed(147),
+    Color::Indexed(114),
+    Color::Indexed(215),
+    Color::Indexed(177),
+    Color::Indexed(80),
+];
+
+/// Author colours readable on light backgrounds
+const LIGHT_AUTHOR_PALETTE: &[Color] = &[
+    Color::Indexed(25),
+    Color::Indexed(166),
+    Color::Indexed(28),
+    Color::Indexed(127),
+    Color::Indexed(136),
+    Color::Indexed(31),
+    Color::Indexed(160),
+    Color::Indexed(61),
+    Color::Indexed(64),
+    Color::Indexed(130),
+    Color::Indexed(90),
+    Color::Indexed(30),
+];
+
+/// Terminal background brightness
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Background {
+    Dark,
+    Light,
+}
+
+impl Background {
+    /// Guess the background from `COLORFGBG` (`"fg;bg"`), assuming dark when unknown
+    pub fn detect() -> Self {
+        std::env::var("COLORFGBG")
+            .ok()
+            .and_then(|value| Self::from_colorfgbg(&value))
+            .unwrap_or(Background::Dark)
+    }
+
+    fn from_colorfgbg(value: &str) -> Option<Self> {
+        let bg: u8 = value.rsplit(';').next()?.parse().ok()?;
+        // ANSI 7 (white) and 9-15 (bright colours) are light backgrounds
+        if bg == 7 || bg >= 9 {
+            Some(Background::Light)
+        } else {
+            Some(Background::Dark)
+        }
+    }
+}
+
+impl Theme {
+    /// Build the theme from config, honouring `NO_COLOR` and the terminal background
+    pub fn from_config(config: &ThemeConfig) -> Self {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        Self::resolve(config, Background::detect(), no_color)
+    }
+
+    fn resolve(config: &ThemeConfig, background: Background, no_color: bool) -> Self {
+        let base = match (config.base, background) {
+            (ThemeName::Dark, _) | (ThemeName::Auto, Background::Dark) => Self::dark(),
+            (ThemeName::Light, _) | (ThemeName::Auto, Background::Light) => Self::light(),
+            (ThemeName::HighContrast, _) => Self::high_contrast(),
+        };
+
+        let theme = base.with_overrides(&config.styles);
+        if no_color {
+            theme.without_colors()
+        } else {
+            theme
+        }
+    }
+
+    pub fn dark() -> Self {
+        let fg = |color| Style::default().fg(color);
+        Self {
+            hash_newest: fg(Color::Yellow),
+            hash_new: fg(Color::White),
+            hash_old: fg(Color::Gray),
+            hash_oldest: fg(Color::DarkGray),
+            author: fg(Color::Blue),
+            author_email: fg(Color::Blue),
+            date: fg(Color::Green),
+            age: fg(Color::Green),
+            line_number: fg(Color::DarkGray),
+            original_path: fg(Color::Magenta),
+            subject: Style::default(),
+            trail_index: fg(Color::Yellow),
+            content: Style::default(),
+            hunk_alternate: Style::default().bg(Color::Indexed(235)),
+            owner_highlight: Style::default().bg(Color::Indexed(24)),
+            selection: Style::default().add_modifier(Modifier::REVERSED),
+            border: Style::default(),
+            title: Style::default(),
+            status_bar: Style::default().bg(Color::DarkGray).fg(Color::White),
+            status_mode: Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+            status_chain: fg(Color::Yellow),
+            status_message: fg(Color::Yellow),
+            diff_hash: fg(Color::Yellow),
+            diff_refs: fg(Color::Yellow),
+            diff_issue: fg(Color::Cyan).add_modifier(Modifier::UNDERLINED),
+            diff_label: Style::default(),
+            help_title: fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            help_section: fg(Color::Blue),
+            author_palette: DARK_AUTHOR_PALETTE,
+            syntax_theme: "base16-ocean.dark",
+            no_color: false,
+        }
+    }
+
+    pub fn light() -> Self {
+        let fg = |color| Style::default().fg(color);
+        Self {
+            hash_newest: fg(Color::Red).add_modifier(Modifier::BOLD),
+            hash_new: fg(Color::Black),
+            hash_old: fg(Color::Indexed(242)),
+            hash_oldest: fg(Color::Indexed(248)),
+            author: fg(Color::Blue),
+            author_email: fg(Color::Blue),
+            date: fg(Color::Indexed(28)),
+            age: fg(Color::Indexed(28)),
+            line_number: fg(Color::Indexed(244)),
+            original_path: fg(Color::Magenta),
+            subject: Style::default(),
+            trail_index: fg(Color::Red),
+            content: Style::default(),
+            hunk_alternate: Style::default().bg(Color::Indexed(254)),
+            owner_highlight: Style::default().bg(Color::Indexed(153)),
+            selection: Style::default().add_modifier(Modifier::REVERSED),
+            border: Style::default(),
+            title: Style::default(),
+            status_bar: Style::default().bg(Color::Indexed(252)).fg(Color::Black),
+            status_mode: Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+            status_chain: fg(Color::Magenta),
+            status_message: fg(Color::Red),
+            diff_hash: fg(Color::Indexed(130)),
+            diff_refs: fg(Color::Indexed(130)),
+            diff_issue: fg(Color::Indexed(25)).add_modifier(Modifier::UNDERLINED),
+            diff_label: Style::default(),
+            help_title: fg(Color::Red).add_modifier(Modifier::BOLD),
+            help_section: fg(Color::Blue),
+            author_palette: LIGHT_AUTHOR_PALETTE,
+            syntax_theme: "InspiredGitHub",
+            no_color: false,
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        let fg = |color| Style::default().fg(color);
+        let bold = |color| Style::default().fg(color).add_modifier(Modifier::BOLD);
+        Self {
+            hash_newest: bold(Color::LightYellow),
+            hash_new: bold(Color::White),
+            hash_old: fg(Color::White),
+            hash_oldest: fg(Color::Gray),
+            author: bold(Color::LightCyan),
+            author_email: fg(Color::LightCyan),
+            date: bold(Color::LightGreen),
+            age: bold(Color::LightGreen),
+            line_number: fg(Color::White),
+            original_path: bold(Color::LightMagenta),
+            subject: fg(Color::White),
+            trail_index: bold(Color::LightYellow),
+            content: fg(Color::White),
+            hunk_alternate: Style::default().bg(Color::Indexed(237)),
+            owner_highlight: Style::default()
+                .bg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+            selection: Style::default()
+                .bg(Color::LightYellow)
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+            border: fg(Color::White),
+            title: bold(Color::White),
+            status_bar: Style::default().bg(Color::White).fg(Color::Black),
+            status_mode: Style::default()
+                .bg(Color::Black)
+                .fg(Color::LightYellow)
+                .add_modifier(Modifier::BOLD),
+            status_chain: Style::default()
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD),
+            status_message: Style::default()
+                .fg(Color::Black)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            diff_hash: bold(Color::LightYellow),
+            diff_refs: bold(Color::LightYellow),
+            diff_issue: bold(Color::LightCyan).add_modifier(Modifier::UNDERLINED),
+            diff_label: bold(Color::White),
+            help_title: bold(Color::LightYellow),
+            help_section: bold(Color::LightCyan),
+            author_palette: DARK_AUTHOR_PALETTE,
+            syntax_theme: "base16-eighties.dark",
+            no_color: false,
+        }
+    }
+
+    /// Hash style for a line whose age ratio is `0.0` (oldest) to `1.0` (newest)
+    pub fn hash_by_age(&self, ratio: f64) -> Style {
+        if ratio < 0.25 {
+            self.hash_oldest
+        } else if ratio < 0.5 {
+            self.hash_old
+        } else if ratio < 0.75 {
+            self.hash_new
+        } else {
+            self.hash_newest
+        }
+    }
+
+    /// Colour for an author, from the slot the session gave them or else from their identity
+    pub fn author_color(&self, key: &str, slots: &AuthorSlots) -> Style {
+        if self.no_color || self.author_palette.is_empty() {
+            return Style::default();
+        }
+        let len = self.author_palette.len();
+        let index = slots
+            .get(key)
+            .filter(|&slot| slot < len)
+            .unwrap_or_else(|| author_hash(key) as usize % len);
+        Style::default().fg(self.author_palette[index])
+    }
+
+    /// Every style slot, in the same order as `ThemeStyles::slots`
+    fn slots_mut(&mut self) -> [&mut Style; 28] {
+        [
+            &mut self.hash_newest,
+            &mut self.hash_new,
+            &mut self.hash_old,
+            &mut self.hash_oldest,
+            &mut self.author,
+            &mut self.author_email,
+            &mut self.date,
+            &mut self.age,
+            &mut self.line_number,
+            &mut self.original_path,
+            &mut self.subject,
+            &mut self.trail_index,
+            &mut self.content,
+            &mut self.hunk_alternate,
+            &mut self.owner_highlight,
+            &mut self.selection,
+            &mut self.border,
+            &mut self.title,
+            &mut self.status_bar,
+            &mut self.status_mode,
+            &mut self.status_chain,
+            &mut self.status_message,
+            &mut self.diff_hash,
+            &mut self.diff_refs,
+            &mut self.diff_issue,
+            &mut self.diff_label,
+            &mut self.help_title,
+            &mut self.help_section,
+        ]
+    }
+
+    fn with_overrides(mut self, styles: &ThemeStyles) -> Self {
+        for (style, config) in self.slots_mut().into_iter().zip(styles.slots()) {
+            if let Some(config) = config {
+                *style = to_style(config);
+            }
+        }
+        self
+    }
+
+    fn without_colors(mut self) -> Self {
+        for style in self.slots_mut() {
+            style.fg = None;
+            style.bg = None;
+        }
+        // Keep the status bar distinguishable without colour
+        self.status_bar = self.status_bar.add_modifier(Modifier::REVERSED);
+        self.no_color = true;
+        self
+    }
+
+    /// Apply the theme's colour policy to externally styled text (e.g. delta output)
+    pub fn filter(&self, style: Style) -> Style {
+        if self.no_color {
+            Style {
+                fg: None,
+                bg: None,
+                ..style
+            }
+        } else {
+            style
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// Author palette slots handed out for the whole session
+///
+/// Each author starts at the slot their identity hashes to and probes past slots already
+/// taken, so they keep one colour across frames and no two authors share a colour until
+/// the palette runs out.
+#[derive(Debug, Default)]
+pub struct AuthorSlots {
+    slots: HashMap<String, usize>,
+}
+
+impl AuthorSlots {
+    /// Give a slot to each author who doesn't have one yet, in the order given
+    pub fn assign(&mut self, authors: &[AuthorLines], palette_len: usize) {
+        for author in authors {
+            if palette_len == 0 || self.slots.len() >= palette_len {
+                return;
+            }
+            if self.slots.contains_key(&author.key) {
+                continue;
+            }
+            let taken: HashSet<usize> = self.slots.values().copied().collect();
+            let start = author_hash(&author.key) as usize % palette_len;
+            let slot = (0..palette_len)
+                .map(|i| (start + i) % palette_len)
+                .find(|slot| !taken.contains(slot))
+                .expect("palette has a free slot");
+            self.slots.insert(author.key.clone(), slot);
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<usize> {
+        self.slots.get(key).copied()
+    }
+}
+
+/// FNV-1a, which unlike std's hasher is stable across releases
+fn author_hash(key: &str) -> u64 {
+    key.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn to_style(config: &StyleConfig) -> Style {
+    let mut style = Style::default();
+    if let Some(fg) = config.fg {
+        style = style.fg(fg.0);
+    }
+    if let Some(bg) = config.bg {
+        style = style.bg(bg.0);
+    }
+
+    let modifiers = [
+        (config.bold, Modifier::BOLD),
+        (config.dim, Modifier::DIM),
+        (config.italic, Modifier::ITALIC),
+        (config.underlined, Modifier::UNDERLINED),
+        (config.reversed, Modifier::REVERSED),
+    ];
+    for (enabled, modifier) in modifiers {
+        if enabled {
+            style = style.add_modifier(modifier);
+        }
+    }
+    style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(toml: &str) -> ThemeConfig {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn auto_follows_background() {
+        let auto = ThemeConfig::default();
+        assert_eq!(
+            Theme::resolve(&auto, Background::Dark, false),
+            Theme::dark()
+        );
+        assert_eq!(
+            Theme::resolve(&auto, Background::Light, false),
+            Theme::light()
+        );
+    }
+
+    #[test]
+    fn explicit_base_ignores_background() {
+        let theme = Theme::resolve(
+            &config(r#"base = "HighContrast""#),
+            Background::Light,
+            false,
+        );
+        assert_eq!(theme, Theme::high_contrast());
+    }
+
+    #[test]
+    fn overrides_accept_names_indexes_and_truecolor() {
+        let theme = Theme::resolve(
+            &config(
+                r##"
+                base = "Dark"
+                [styles]
+                author = { fg = "#ff8800", bold = true }
+                date = { fg = "208", bg = "black" }
+                "##,
+            ),
+            Background::Dark,
+            false,
+        );
+        assert_eq!(
+            theme.author,
+            Style::default()
+                .fg(Color::Rgb(0xff, 0x88, 0x00))
+                .add_modifier(Modifier::BOLD)
+        );
+        assert_eq!(
+            theme.date,
+            Style::default().fg(Color::Indexed(208)).bg(Color::Black)
+        );
+        assert_eq!(theme.subject, Theme::dark().subject);
+    }
+
+    #[test]
+    fn invalid_color_is_rejected() {
+        assert!(toml::from_str::<ThemeConfig>("[styles]\nauthor = { fg = \"#zz\" }").is_err());
+    }
+
+    #[test]
+    fn no_color_keeps_only_modifiers() {
+        let theme = Theme::resolve(&ThemeConfig::default(), Background::Dark, true);
+        assert_eq!(theme.author, Style::default());
+        assert_eq!(theme.status_mode.fg, None);
+        assert!(theme.status_mode.add_modifier.contains(Modifier::BOLD));
+        assert!(theme.selection.add_modifier.contains(Modifier::REVERSED));
+        assert_eq!(
+            theme.filter(Style::default().fg(Color::Red)),
+            Style::default()
+        );
+    }
+
+    #[test]
+    fn author_slots_are_stable_and_distinct() {
+        let theme = Theme::dark();
+        let len = DARK_AUTHOR_PALETTE.len();
+        let authors: Vec<AuthorLines> = (0..len + 2)
+            .map(|i| AuthorLines {
+                key: format!("author{}@example.com", i),
+                author: format!("Author {}", i),
+                raw_author: None,
+                lines: 1,
+            })
+            .collect();
+
+        let mut slots = AuthorSlots::default();
+        slots.assign(&authors[..3], len);
+        let first: Vec<Style> = authors[..3]
+            .iter()
+            .map(|a| theme.author_color(&a.key, &slots))
+            .collect();
+
+        // Later frames don't move authors who already have a colour
+        slots.assign(&authors, len);
+        for (author, style) in authors[..3].iter().zip(&first) {
+            assert_eq!(theme.author_color(&author.key, &slots), *style);
+        }
+        let colors: HashSet<Color> = authors[..len]
+            .iter()
+            .filter_map(|a| theme.author_color(&a.key, &slots).fg)
+            .collect();
+        assert_eq!(colors.len(), len);
+
+        // Once the palette is used up the colour comes from the identity alone
+        let late = &authors[len].key;
+        assert_eq!(slots.get(late), None);
+        assert_eq!(
+            theme.author_color(late, &slots),
+            theme.author_color(late, &AuthorSlots::default())
+        );
+
+        let theme = Theme::resolve(&ThemeConfig::default(), Background::Dark, true);
+        assert_eq!(
+            theme.author_color(&authors[0].key, &slots),
+            Style::default()
+        );
+    }
+
+    #[test]
+    fn colorfgbg_detects_light_backgrounds() {
+        assert_eq!(Background::from_colorfgbg("0;15"), Some(Background::Light));
+        assert_eq!(
+            Background::from_colorfgbg("15;default;0"),
+            Some(Background::Dark)
+        );
+        assert_eq!(Background::from_colorfgbg("garbage"), None);
+    }
+}
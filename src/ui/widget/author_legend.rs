@@ -0,0 +1,99 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Widget},
+};
+
+use crate::domain::blame::AuthorLines;
+use crate::ui::theme::{AuthorSlots, Theme};
+
+/// Overlay listing the frame's authors with their colour and line count
+pub struct AuthorLegend<'a> {
+    authors: &'a [AuthorLines],
+    slots: &'a AuthorSlots,
+    theme: &'a Theme,
+    show_raw_identity: bool,
+}
+
+impl<'a> AuthorLegend<'a> {
+    pub fn new(authors: &'a [AuthorLines], slots: &'a AuthorSlots, theme: &'a Theme) -> Self {
+        Self {
+            authors,
+            slots,
+            theme,
+            show_raw_identity: false,
+        }
+    }
+
+    pub fn with_raw_identity(mut self, show_raw_identity: bool) -> Self {
+        self.show_raw_identity = show_raw_identity;
+        self
+    }
+}
+
+impl Widget for AuthorLegend<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let authors = self.authors;
+        if authors.is_empty() {
+            return;
+        }
+
+        let count_width = authors[0].lines.to_string().len();
+        let rows: Vec<(String, String, &str)> = authors
+            .iter()
+            .map(|a| {
+                (
+                    a.author_label(self.show_raw_identity),
+                    format!("{:>count_width$}", a.lines),
+                    a.key.as_str(),
+                )
+            })
+            .collect();
+        let name_width = rows
+            .iter()
+            .map(|(name, _, _)| name.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        // Swatch, name, gap, count, plus borders
+        let width = ((2 + name_width + 1 + count_width + 2) as u16)
+            .max(12)
+            .min(area.width.saturating_sub(2));
+        let height = (rows.len() as u16 + 2).min(area.height.saturating_sub(2));
+        if width < 6 || height < 3 {
+            return;
+        }
+
+        // Top-right corner, clear of the blame view's border
+        let popup_area = Rect::new(area.x + area.width - width - 1, area.y + 1, width, height);
+        Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.theme.border)
+            .title(Span::styled(" Authors ", self.theme.title));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        let visible = inner.height as usize;
+        let hidden = rows.len().saturating_sub(visible);
+        let name_space = (inner.width as usize).saturating_sub(2 + 1 + count_width);
+
+        for (i, (name, count, key)) in rows.iter().take(visible).enumerate() {
+            let y = inner.y + i as u16;
+            let line = if hidden > 0 && i == visible - 1 {
+                Line::from(format!("  +{} more", hidden + 1))
+            } else {
+                let style = self.theme.author_color(key, self.slots);
+                let name: String = name.chars().take(name_space).collect();
+                Line::from(vec![
+                    Span::styled("█ ", style),
+                    Span::styled(format!("{:name_space$} ", name), style),
+                    Span::raw(count.clone()),
+                ])
+            };
+            buf.set_line(inner.x, y, &line, inner.width);
+        }
+    }
+}
@@ -1,15 +1,34 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, StatefulWidget, Widget},
 };
 
-use crate::domain::BlameFrame;
+use crate::config::{BlameConfig, BlockStyle, Coloring, ColumnKind, HeatmapTarget};
+use crate::domain::date::{self, relative_age};
+use crate::domain::{BlameFrame, BlameStack, Owner};
+use crate::issues::IssueHighlight;
+use crate::ui::heatmap::Heatmap;
+use crate::ui::theme::{AuthorSlots, Theme};
+
+/// Width of the heatmap gutter bar, including its gap
+const GUTTER_WIDTH: u16 = 2;
 
 pub struct BlameView<'a> {
     frame: &'a BlameFrame,
+    config: &'a BlameConfig,
+    theme: &'a Theme,
+    trail: Option<&'a BlameStack>,
+    show_raw_identity: bool,
+    show_subject: bool,
+    coloring: Coloring,
+    highlight: Option<&'a [Line<'static>]>,
+    block_style: BlockStyle,
+    owner: Option<&'a Owner>,
+    issue: Option<&'a IssueHighlight>,
+    author_slots: Option<&'a AuthorSlots>,
 }
 
 pub struct BlameViewState {
@@ -17,8 +36,71 @@ pub struct BlameViewState {
 }
 
 impl<'a> BlameView<'a> {
-    pub fn new(frame: &'a BlameFrame) -> Self {
-        Self { frame }
+    pub fn new(frame: &'a BlameFrame, config: &'a BlameConfig, theme: &'a Theme) -> Self {
+        Self {
+            frame,
+            config,
+            theme,
+            trail: None,
+            show_raw_identity: false,
+            show_subject: false,
+            coloring: Coloring::default(),
+            highlight: None,
+            block_style: BlockStyle::default(),
+            owner: None,
+            issue: None,
+            author_slots: None,
+        }
+    }
+
+    /// Session-wide author colours for `Coloring::Author`
+    pub fn with_author_slots(mut self, author_slots: &'a AuthorSlots) -> Self {
+        self.author_slots = Some(author_slots);
+        self
+    }
+
+    /// Highlight the lines of this author or commit
+    pub fn with_owner(mut self, owner: Option<&'a Owner>) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Highlight the lines whose commit mentions this issue
+    pub fn with_issue(mut self, issue: Option<&'a IssueHighlight>) -> Self {
+        self.issue = issue;
+        self
+    }
+
+    pub fn with_block_style(mut self, block_style: BlockStyle) -> Self {
+        self.block_style = block_style;
+        self
+    }
+
+    /// Syntax-highlighted content, one line per entry
+    pub fn with_highlight(mut self, highlight: Option<&'a [Line<'static>]>) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    pub fn with_coloring(mut self, coloring: Coloring) -> Self {
+        self.coloring = coloring;
+        self
+    }
+
+    /// Show the commit subject in the author column
+    pub fn with_subject(mut self, show_subject: bool) -> Self {
+        self.show_subject = show_subject;
+        self
+    }
+
+    pub fn with_trail(mut self, trail: &'a BlameStack) -> Self {
+        self.trail = Some(trail);
+        self
+    }
+
+    pub fn with_raw_identity(mut self, show_raw_identity: bool) -> Self {
+        self.show_raw_identity = show_raw_identity;
+        self
     }
 }
 
@@ -26,25 +108,51 @@ impl<'a> StatefulWidget for BlameView<'a> {
     type State = BlameViewState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let theme = self.theme;
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(format!(" {} ", self.frame.file_path.display()));
+            .border_style(theme.border)
+            .title(Span::styled(
+                format!(" {} ", self.frame.file_path.display()),
+                theme.title,
+            ));
 
         let inner = block.inner(area);
         block.render(area, buf);
 
         let visible_lines = inner.height as usize;
-        let total_lines = self.frame.entries.len();
+
+        // Rows to draw: every entry, plus a separator before each hunk but the first
+        let mut rows: Vec<Option<usize>> = Vec::with_capacity(self.frame.entries.len());
+        for line in 0..self.frame.entries.len() {
+            if self.block_style == BlockStyle::Separator
+                && line > 0
+                && self.frame.is_hunk_start(line)
+            {
+                rows.push(None);
+            }
+            rows.push(Some(line));
+        }
+        let total_rows = rows.len();
+        let selected_row = rows
+            .iter()
+            .position(|row| *row == Some(self.frame.selected_line))
+            .unwrap_or(0);
+        // Show the separator above the selected hunk when scrolling up to it
+        let top_row = match selected_row.checked_sub(1) {
+            Some(above) if rows[above].is_none() => above,
+            _ => selected_row,
+        };
 
         // Adjust scroll to keep selected line visible
-        if self.frame.selected_line < state.scroll_offset {
-            state.scroll_offset = self.frame.selected_line;
-        } else if self.frame.selected_line >= state.scroll_offset + visible_lines {
-            state.scroll_offset = self.frame.selected_line - visible_lines + 1;
+        if top_row < state.scroll_offset {
+            state.scroll_offset = top_row;
+        } else if selected_row >= state.scroll_offset + visible_lines {
+            state.scroll_offset = selected_row - visible_lines + 1;
         }
 
-        let start = state.scroll_offset;
-        let end = (start + visible_lines).min(total_lines);
+        let start = state.scroll_offset.min(total_rows);
+        let end = (start + visible_lines).min(total_rows);
 
         // Calculate min/max timestamps for color gradient
         let (min_ts, max_ts) = self
@@ -55,136 +163,250 @@ impl<'a> StatefulWidget for BlameView<'a> {
                 (min.min(e.timestamp), max.max(e.timestamp))
             });
 
-        for (i, entry) in self.frame.entries[start..end].iter().enumerate() {
+        let date_format = self.config.date_format();
+        let now = date::now();
+
+        // The heatmap needs colours, so NO_COLOR falls back to age colouring
+        let heatmap = (self.coloring == Coloring::Heatmap && !theme.no_color)
+            .then(|| Heatmap::new(&self.config.heatmap, &self.frame.entries, now));
+        let target = self.config.heatmap.target;
+        let by_author = self.coloring == Coloring::Author && !theme.no_color;
+        let no_slots = AuthorSlots::default();
+        let author_slots = self.author_slots.unwrap_or(&no_slots);
+        let gutter = by_author || (heatmap.is_some() && target == HeatmapTarget::Gutter);
+        let columns = if gutter {
+            self.config
+                .fit_columns(inner.width.saturating_sub(GUTTER_WIDTH))
+        } else {
+            self.config.fit_columns(inner.width)
+        };
+
+        for (i, row) in rows[start..end].iter().enumerate() {
             let y = inner.y + i as u16;
-            let line_index = start + i;
+            let Some(line_index) = *row else {
+                let rule = "─".repeat(inner.width as usize);
+                buf.set_string(inner.x, y, rule, theme.border);
+                continue;
+            };
+            let entry = &self.frame.entries[line_index];
             let is_selected = line_index == self.frame.selected_line;
 
-            let base_style = if is_selected {
-                Style::default().add_modifier(Modifier::REVERSED)
+            // Only the first line of a hunk repeats the commit's metadata
+            let continuation =
+                self.block_style != BlockStyle::Off && !self.frame.is_hunk_start(line_index);
+            let row_style = if self.owner.is_some_and(|owner| owner.owns(entry))
+                || self
+                    .issue
+                    .is_some_and(|issue| issue.contains(&entry.commit_hash))
+            {
+                theme.owner_highlight
+            } else if self.block_style == BlockStyle::Alternate
+                && self
+                    .frame
+                    .hunk_index(line_index)
+                    .is_some_and(|hunk| hunk % 2 == 1)
+            {
+                theme.hunk_alternate
             } else {
                 Style::default()
             };
 
-            // Fill entire line with REVERSED background first (for selected line)
-            if is_selected {
-                let reversed_style = Style::default().add_modifier(Modifier::REVERSED);
-                for x in inner.x..inner.x + inner.width {
-                    buf[(x, y)].set_style(reversed_style);
+            let heat_color = heatmap.as_ref().map(|h| h.color(entry.timestamp));
+            let background = heat_color.filter(|_| target == HeatmapTarget::Background);
+            let author_style = if by_author {
+                theme.author_color(&entry.author_key(), author_slots)
+            } else {
+                theme.author
+            };
+
+            let styled = |style: Style| {
+                let style = row_style.patch(style);
+                let style = match background {
+                    Some(color) => style.bg(color),
+                    None => style,
+                };
+                if is_selected {
+                    style.patch(theme.selection)
+                } else {
+                    style
+                }
+            };
+
+            // Fill entire line with the heatmap background and selection style first
+            for x in inner.x..inner.x + inner.width {
+                buf[(x, y)].set_style(row_style);
+                if let Some(color) = background {
+                    buf[(x, y)].set_bg(color);
+                }
+                if is_selected {
+                    buf[(x, y)].set_style(theme.selection);
                 }
             }
 
-            // Commit hash with age-based color (newer = brighter yellow, older = darker)
-            let hash_color = age_to_color(entry.timestamp, min_ts, max_ts);
-            let hash_span = Span::styled(
-                format!("{} ", entry.commit_hash.short()),
-                base_style.fg(hash_color),
-            );
-
-            // Author (blue, truncated to 12 chars)
-            let author = truncate(&entry.author, 12);
-            let author_span = Span::styled(format!("{:>12} ", author), base_style.fg(Color::Blue));
-
-            // Timestamp (green)
-            let timestamp_span = Span::styled(
-                format!("{} ", format_timestamp(entry.timestamp)),
-                base_style.fg(Color::Green),
-            );
-
-            // Line number (dark gray)
-            let line_num_span = Span::styled(
-                format!("{:>5} ", entry.line_number),
-                base_style.fg(Color::DarkGray),
-            );
-
-            // Content
-            let content_span = Span::styled(&entry.content, base_style);
-
-            let line = Line::from(vec![
-                hash_span,
-                author_span,
-                timestamp_span,
-                line_num_span,
-                content_span,
-            ]);
-            buf.set_line(inner.x, y, &line, inner.width);
+            let gutter_color = if by_author {
+                author_style.fg
+            } else {
+                heat_color.filter(|_| gutter)
+            };
+            let gutter_span =
+                gutter_color.map(|color| Span::styled("▌ ", Style::default().fg(color)));
+
+            let spans: Vec<Span> = gutter_span
+                .into_iter()
+                .chain(columns.iter().flat_map(|&(kind, width)| {
+                    let (text, style) = match kind {
+                        ColumnKind::Content => {
+                            let highlighted = self.highlight.and_then(|h| h.get(line_index));
+                            let spans = match highlighted {
+                                Some(line) => clip(&line.spans, width),
+                                None => clip(&[Span::raw(entry.content.as_str())], width),
+                            };
+                            return spans
+                                .into_iter()
+                                .map(|span| {
+                                    let style = styled(theme.content.patch(span.style));
+                                    span.style(style)
+                                })
+                                .collect();
+                        }
+                        // Commit hash with age-based color (newer = brighter yellow, older = darker)
+                        // Boundary lines get git's `^` prefix in place of the last hash character
+                        ColumnKind::Hash => {
+                            let short = entry.commit_hash.short();
+                            let hash = if entry.boundary {
+                                format!("^{}", &short[..short.len().saturating_sub(1)])
+                            } else {
+                                short.to_string()
+                            };
+                            let ratio = age_ratio(entry.timestamp, min_ts, max_ts);
+                            (fit(&hash, width, false), theme.hash_by_age(ratio))
+                        }
+                        ColumnKind::Author if self.show_subject => {
+                            (fit(&entry.summary, width, false), theme.subject)
+                        }
+                        ColumnKind::Author => (
+                            fit(&entry.author_label(self.show_raw_identity), width, true),
+                            author_style,
+                        ),
+                        ColumnKind::AuthorEmail => {
+                            let style = if by_author {
+                                author_style
+                            } else {
+                                theme.author_email
+                            };
+                            (fit(&entry.author_email, width, false), style)
+                        }
+                        ColumnKind::Date => (
+                            fit(
+                                &date_format.format(entry.timestamp, entry.offset_minutes, now),
+                                width,
+                                false,
+                            ),
+                            theme.date,
+                        ),
+                        ColumnKind::Age => (
+                            fit(&relative_age(entry.timestamp, now), width, false),
+                            theme.age,
+                        ),
+                        ColumnKind::LineNumber => (
+                            fit(&entry.line_number.to_string(), width, true),
+                            theme.line_number,
+                        ),
+                        ColumnKind::OriginalLineNumber => (
+                            fit(&entry.original_line_number.to_string(), width, true),
+                            theme.line_number,
+                        ),
+                        ColumnKind::OriginalPath => {
+                            let path = entry
+                                .original_path
+                                .as_deref()
+                                .unwrap_or(&self.frame.file_path);
+                            (
+                                fit(&path.to_string_lossy(), width, false),
+                                theme.original_path,
+                            )
+                        }
+                        ColumnKind::Subject => (fit(&entry.summary, width, false), theme.subject),
+                        ColumnKind::TrailIndex => {
+                            let position = self
+                                .trail
+                                .and_then(|t| t.trail_position(&entry.commit_hash))
+                                .map(|p| format!("@{}", p))
+                                .unwrap_or_default();
+                            (fit(&position, width, true), theme.trail_index)
+                        }
+                    };
+                    let text = if continuation && describes_commit(kind) {
+                        " ".repeat(width as usize)
+                    } else {
+                        text
+                    };
+                    vec![Span::styled(format!("{} ", text), styled(style))]
+                }))
+                .collect();
+
+            buf.set_line(inner.x, y, &Line::from(spans), inner.width);
         }
     }
 }
 
-fn truncate(s: &str, max_len: usize) -> String {
-    if s.chars().count() <= max_len {
+/// Truncate or pad `s` to exactly `width` characters
+fn fit(s: &str, width: u16, align_right: bool) -> String {
+    let width = width as usize;
+    let text = if s.chars().count() <= width {
         s.to_string()
+    } else if width > 2 {
+        let truncated: String = s.chars().take(width - 2).collect();
+        format!("{}..", truncated)
     } else {
-        let truncated: String = s.chars().take(max_len - 2).collect();
-        format!("{}.. ", truncated)
+        s.chars().take(width).collect()
+    };
+
+    if align_right {
+        format!("{:>width$}", text)
+    } else {
+        format!("{:<width$}", text)
     }
 }
 
-fn format_timestamp(timestamp: i64) -> String {
-    use std::time::{Duration, UNIX_EPOCH};
-
-    let datetime = UNIX_EPOCH + Duration::from_secs(timestamp as u64);
-    let secs = datetime
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs();
-
-    // Convert to date components
-    let days = secs / 86400;
-    let mut year = 1970;
-    let mut remaining_days = days;
+/// Columns that describe the commit rather than the line, blanked after a hunk's first line
+fn describes_commit(kind: ColumnKind) -> bool {
+    !matches!(
+        kind,
+        ColumnKind::LineNumber | ColumnKind::OriginalLineNumber | ColumnKind::Content
+    )
+}
 
-    loop {
-        let days_in_year = if is_leap_year(year) { 366 } else { 365 };
-        if remaining_days < days_in_year {
+/// Cut styled text to exactly `width` characters, padding with spaces
+fn clip<'s>(spans: &[Span<'s>], width: u16) -> Vec<Span<'s>> {
+    let mut remaining = width as usize;
+    let mut clipped = Vec::new();
+    for span in spans {
+        if remaining == 0 {
             break;
         }
-        remaining_days -= days_in_year;
-        year += 1;
-    }
-
-    let days_in_months: [u64; 12] = if is_leap_year(year) {
-        [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    } else {
-        [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    };
-
-    let mut month = 1;
-    for days_in_month in days_in_months {
-        if remaining_days < days_in_month {
-            break;
+        let len = span.content.chars().count();
+        if len <= remaining {
+            clipped.push(span.clone());
+            remaining -= len;
+        } else {
+            let text: String = span.content.chars().take(remaining).collect();
+            clipped.push(Span::styled(text, span.style));
+            remaining = 0;
         }
-        remaining_days -= days_in_month;
-        month += 1;
     }
-
-    let day = remaining_days + 1;
-
-    format!("{:04}-{:02}-{:02}", year, month, day)
-}
-
-fn is_leap_year(year: u64) -> bool {
-    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+    if remaining > 0 {
+        clipped.push(Span::raw(" ".repeat(remaining)));
+    }
+    clipped
 }
 
-/// Convert timestamp age to color using terminal colors (newer = brighter)
-fn age_to_color(timestamp: i64, min_ts: i64, max_ts: i64) -> Color {
+/// Position of `timestamp` between the oldest and newest line, from 0.0 to 1.0
+fn age_ratio(timestamp: i64, min_ts: i64, max_ts: i64) -> f64 {
     let range = max_ts - min_ts;
-    let ratio = if range == 0 {
+    if range == 0 {
         1.0
     } else {
         (timestamp - min_ts) as f64 / range as f64
-    };
-
-    // Use terminal colors: DarkGray -> Gray -> White -> Yellow
-    if ratio < 0.25 {
-        Color::DarkGray
-    } else if ratio < 0.5 {
-        Color::Gray
-    } else if ratio < 0.75 {
-        Color::White
-    } else {
-        Color::Yellow
     }
 }
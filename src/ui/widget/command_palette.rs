@@ -0,0 +1,71 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Widget},
+};
+
+use crate::ui::command_line::Completion;
+use crate::ui::theme::Theme;
+
+/// Completions for the command line, shown just above the status bar
+pub struct CommandPalette<'a> {
+    completion: &'a Completion,
+    theme: &'a Theme,
+}
+
+impl<'a> CommandPalette<'a> {
+    const MAX_ROWS: usize = 8;
+
+    pub fn new(completion: &'a Completion, theme: &'a Theme) -> Self {
+        Self { completion, theme }
+    }
+}
+
+impl Widget for CommandPalette<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let matches = &self.completion.matches;
+        if matches.is_empty() || area.height < 4 {
+            return;
+        }
+
+        // Anchor to the bottom left, leaving the status bar visible
+        let longest = matches.iter().map(|m| m.len()).max().unwrap_or(0);
+        let width = (longest as u16 + 4).clamp(32, area.width);
+        let rows = matches.len().min(Self::MAX_ROWS) as u16;
+        let height = (rows + 2).min(area.height - 1);
+        let popup_area = Rect::new(area.x, area.bottom() - 1 - height, width, height);
+
+        Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.theme.border)
+            .title(Span::styled(
+                format!(" {} matches (Tab to complete) ", matches.len()),
+                self.theme.title,
+            ));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        // Keep the match Tab inserted visible
+        let visible_lines = inner.height as usize;
+        let selected = self.completion.selected;
+        let start = selected.map_or(0, |s| (s + 1).saturating_sub(visible_lines));
+
+        for (i, name) in matches.iter().enumerate().skip(start).take(visible_lines) {
+            let y = inner.y + (i - start) as u16;
+            let mut line = Line::from(Span::raw(format!(" {}", name)));
+
+            if Some(i) == selected {
+                for x in inner.x..inner.x + inner.width {
+                    buf[(x, y)].set_style(self.theme.selection);
+                }
+                for span in &mut line.spans {
+                    span.style = span.style.patch(self.theme.selection);
+                }
+            }
+            buf.set_line(inner.x, y, &line, inner.width);
+        }
+    }
+}
@@ -2,16 +2,29 @@ use ansi_to_tui::IntoText;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, StatefulWidget, Widget},
 };
 
-use crate::domain::CommitInfo;
+use crate::config::{ForgeKind, HeaderField};
+use crate::domain::date::{self, DateFormat, DateStyle};
+use crate::domain::{CommitInfo, Signature};
+use crate::forge::PullRequest;
+use crate::issues::IssueLinker;
+use crate::ui::theme::Theme;
 
 pub struct DiffView<'a> {
     lines: &'a [String],
     commit_info: Option<&'a CommitInfo>,
+    pull_request: Option<&'a PullRequest>,
+    issues: Option<&'a IssueLinker>,
+    title: &'a str,
+    theme: &'a Theme,
+    header: &'a [HeaderField],
+    date_format: Option<&'a DateFormat>,
+    show_raw_identity: bool,
+    wrap: bool,
 }
 
 pub struct DiffViewState {
@@ -20,8 +33,56 @@ pub struct DiffViewState {
 }
 
 impl<'a> DiffView<'a> {
-    pub fn new(lines: &'a [String], commit_info: Option<&'a CommitInfo>) -> Self {
-        Self { lines, commit_info }
+    pub fn new(lines: &'a [String], commit_info: Option<&'a CommitInfo>, theme: &'a Theme) -> Self {
+        Self {
+            lines,
+            commit_info,
+            pull_request: None,
+            issues: None,
+            title: "Diff",
+            theme,
+            header: &[],
+            date_format: None,
+            show_raw_identity: false,
+            wrap: false,
+        }
+    }
+
+    pub fn with_date_format(mut self, date_format: &'a DateFormat) -> Self {
+        self.date_format = Some(date_format);
+        self
+    }
+
+    pub fn with_header(mut self, header: &'a [HeaderField]) -> Self {
+        self.header = header;
+        self
+    }
+
+    pub fn with_pull_request(mut self, pull_request: Option<&'a PullRequest>) -> Self {
+        self.pull_request = pull_request;
+        self
+    }
+
+    pub fn with_title(mut self, title: &'a str) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Highlight issue references in the message
+    pub fn with_issues(mut self, issues: &'a IssueLinker) -> Self {
+        self.issues = Some(issues);
+        self
+    }
+
+    pub fn with_raw_identity(mut self, show_raw_identity: bool) -> Self {
+        self.show_raw_identity = show_raw_identity;
+        self
+    }
+
+    /// Continue long lines on the following rows instead of cutting them off
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
     }
 
     fn header_lines(&self) -> Vec<Line<'a>> {
@@ -29,26 +90,117 @@ impl<'a> DiffView<'a> {
             return vec![];
         };
 
-        let yellow = Style::default().fg(Color::Yellow);
-        let normal = Style::default();
-
-        vec![
-            Line::from(vec![
-                Span::styled("commit ", normal),
-                Span::styled(info.hash.as_str(), yellow),
-            ]),
+        let theme = self.theme;
+        let labeled = |label: &str, value: String| -> Line<'a> {
             Line::from(vec![
-                Span::styled("Author: ", normal),
-                Span::raw(&info.author),
-            ]),
-            Line::from(vec![
-                Span::styled("Date:   ", normal),
-                Span::raw(format_timestamp(info.timestamp)),
-            ]),
-            Line::raw(""),
-            Line::from(vec![Span::styled("    ", normal), Span::raw(&info.message)]),
-            Line::raw(""),
-        ]
+                Span::styled(format!("{:12}", label), theme.diff_label),
+                Span::raw(value),
+            ])
+        };
+        let mut lines = Vec::new();
+
+        for field in self.header {
+            match field {
+                HeaderField::Commit => lines.push(Line::from(vec![
+                    Span::styled("commit ", theme.diff_label),
+                    Span::styled(info.hash.as_str(), theme.diff_hash),
+                ])),
+                HeaderField::Refs if !info.refs.is_empty() => lines.push(Line::from(vec![
+                    Span::styled(format!("{:12}", "Refs:"), theme.diff_label),
+                    Span::styled(info.refs.join(", "), theme.diff_refs),
+                ])),
+                HeaderField::PullRequest => {
+                    if let Some(pr) = self.pull_request {
+                        let label = match pr.kind {
+                            ForgeKind::GitLab => "MR:",
+                            _ => "PR:",
+                        };
+                        lines.push(Line::from(vec![
+                            Span::styled(format!("{:12}", label), theme.diff_label),
+                            Span::styled(pr.label(), theme.diff_refs),
+                            Span::raw(format!(" {}", pr.url)),
+                        ]));
+                    }
+                }
+                HeaderField::Parents if !info.parents.is_empty() => {
+                    let label = if info.parents.len() > 1 {
+                        "Merge:"
+                    } else {
+                        "Parent:"
+                    };
+                    let parents: Vec<&str> = info.parents.iter().map(|p| p.short()).collect();
+                    lines.push(labeled(label, parents.join(" ")));
+                }
+                HeaderField::Author => {
+                    lines.push(labeled("Author:", self.identity(&info.author)));
+                }
+                HeaderField::AuthorDate => {
+                    lines.push(labeled("AuthorDate:", self.date(&info.author)));
+                }
+                HeaderField::Committer => {
+                    lines.push(labeled("Commit:", self.identity(&info.committer)));
+                }
+                HeaderField::CommitDate => {
+                    lines.push(labeled("CommitDate:", self.date(&info.committer)))
+                }
+                HeaderField::Message => {
+                    lines.push(Line::raw(""));
+                    for message_line in info.message.trim_end().lines() {
+                        lines.push(self.message_line(message_line));
+                    }
+                    lines.push(Line::raw(""));
+                }
+                HeaderField::Trailers => {
+                    for (key, value) in &info.trailers {
+                        lines.push(labeled(&format!("{}:", key), value.clone()));
+                    }
+                }
+                HeaderField::Refs | HeaderField::Parents => {}
+            }
+        }
+
+        // Separate the header from the diff unless it already ends in a blank line
+        if lines.last().is_some_and(|l| l.width() != 0) {
+            lines.push(Line::raw(""));
+        }
+
+        lines
+    }
+
+    fn message_line(&self, text: &str) -> Line<'a> {
+        let mut spans = vec![Span::raw("    ")];
+        let mut end = 0;
+        for issue in self.issues.map(|i| i.find(text)).unwrap_or_default() {
+            spans.push(Span::raw(text[end..issue.range.start].to_string()));
+            spans.push(Span::styled(
+                text[issue.range.clone()].to_string(),
+                self.theme.diff_issue,
+            ));
+            end = issue.range.end;
+        }
+        spans.push(Span::raw(text[end..].to_string()));
+        Line::from(spans)
+    }
+
+    /// Diff line converted from delta's ANSI output
+    fn diff_line(&self, idx: usize) -> Line<'a> {
+        let line_content = &self.lines[idx];
+        let text = line_content
+            .as_bytes()
+            .into_text()
+            .unwrap_or_else(|_| line_content.as_str().into());
+        text.lines.into_iter().next().unwrap_or_default()
+    }
+
+    fn date(&self, sig: &Signature) -> String {
+        let default = DateFormat::new(DateStyle::Iso);
+        self.date_format
+            .unwrap_or(&default)
+            .format(sig.timestamp, sig.offset_minutes, date::now())
+    }
+
+    fn identity(&self, sig: &Signature) -> String {
+        format!("{} <{}>", sig.name_label(self.show_raw_identity), sig.email)
     }
 }
 
@@ -56,7 +208,11 @@ impl<'a> StatefulWidget for DiffView<'a> {
     type State = DiffViewState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let block = Block::default().borders(Borders::ALL).title(" Diff ");
+        let theme = self.theme;
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border)
+            .title(Span::styled(format!(" {} ", self.title), theme.title));
 
         let inner = block.inner(area);
         block.render(area, buf);
@@ -65,6 +221,7 @@ impl<'a> StatefulWidget for DiffView<'a> {
         let header_len = header.len();
         let visible_lines = inner.height as usize;
         let total_lines = header_len + self.lines.len();
+        let width = inner.width as usize;
 
         // Clamp selected line (only diff lines are selectable, not header)
         let selectable_lines = self.lines.len();
@@ -72,130 +229,141 @@ impl<'a> StatefulWidget for DiffView<'a> {
             state.selected_line = selectable_lines.saturating_sub(1);
         }
 
+        // Header line or diff line at a position, and the rows it takes
+        let line_at = |idx: usize| -> Line<'_> {
+            match idx.checked_sub(header_len) {
+                Some(diff_idx) => self.diff_line(diff_idx),
+                None => header[idx].clone(),
+            }
+        };
+        let rows = |line: &Line| -> usize {
+            if self.wrap && width > 0 {
+                line.width().div_ceil(width).max(1)
+            } else {
+                1
+            }
+        };
+
         // Adjust scroll to keep selected line visible (account for header)
         let selected_visual = header_len + state.selected_line;
         if selected_visual < state.scroll_offset {
             state.scroll_offset = selected_visual;
-        } else if selected_visual >= state.scroll_offset + visible_lines {
-            state.scroll_offset = selected_visual - visible_lines + 1;
+        } else if !self.wrap {
+            if selected_visual >= state.scroll_offset + visible_lines {
+                state.scroll_offset = selected_visual - visible_lines + 1;
+            }
+        } else {
+            // Scroll until every row of the selected line fits
+            let mut used: usize = (state.scroll_offset..=selected_visual)
+                .map(|idx| rows(&line_at(idx)))
+                .sum();
+            while used > visible_lines && state.scroll_offset < selected_visual {
+                used -= rows(&line_at(state.scroll_offset));
+                state.scroll_offset += 1;
+            }
         }
 
-        let start = state.scroll_offset;
-        let end = (start + visible_lines).min(total_lines);
-
-        let mut y_offset = 0u16;
-
-        // Render visible header lines
-        let header_start = start.min(header_len);
-        let header_end = end.min(header_len);
-        for (idx, line) in header
-            .iter()
-            .enumerate()
-            .skip(header_start)
-            .take(header_end.saturating_sub(header_start))
-        {
-            let y = inner.y + (idx - start) as u16;
-            buf.set_line(inner.x, y, line, inner.width);
-            y_offset = (idx - start + 1) as u16;
-        }
+        let mut y = inner.y;
+        for idx in state.scroll_offset..total_lines {
+            if y >= inner.bottom() {
+                break;
+            }
+            let is_selected = idx == selected_visual;
+            let mut line = line_at(idx);
 
-        // Render visible diff lines
-        let diff_start = start.saturating_sub(header_len);
-        let diff_end = end.saturating_sub(header_len);
-        for (idx, line_content) in self
-            .lines
-            .iter()
-            .enumerate()
-            .skip(diff_start)
-            .take(diff_end.saturating_sub(diff_start))
-        {
-            let y = inner.y + y_offset;
-            y_offset += 1;
-
-            let is_selected = idx == state.selected_line;
-
-            // Fill entire line with REVERSED background first (for selected line)
-            if is_selected {
-                let reversed_style = Style::default().add_modifier(Modifier::REVERSED);
-                for x in inner.x..inner.x + inner.width {
-                    buf[(x, y)].set_style(reversed_style);
+            // Drop delta's colours under NO_COLOR and apply the selection style
+            if idx >= header_len {
+                for span in &mut line.spans {
+                    span.style = theme.filter(span.style);
+                    if is_selected {
+                        span.style = span.style.patch(theme.selection);
+                    }
                 }
             }
 
-            // Convert ANSI to styled Line
-            let text = line_content
-                .as_bytes()
-                .into_text()
-                .unwrap_or_else(|_| line_content.as_str().into());
-
-            let mut line: Line = if text.lines.is_empty() {
-                Line::raw("")
+            let wrapped = if self.wrap {
+                wrap_line(&line, width)
             } else {
-                text.lines.into_iter().next().unwrap_or_default()
+                vec![line]
             };
-
-            // Apply REVERSED modifier for selected line
-            if is_selected {
-                for span in &mut line.spans {
-                    span.style = span.style.add_modifier(Modifier::REVERSED);
+            for row in wrapped {
+                if y >= inner.bottom() {
+                    break;
+                }
+                // Fill entire row with the selection style first (for selected line)
+                if is_selected {
+                    for x in inner.x..inner.x + inner.width {
+                        buf[(x, y)].set_style(theme.selection);
+                    }
                 }
+                buf.set_line(inner.x, y, &row, inner.width);
+                y += 1;
             }
-
-            buf.set_line(inner.x, y, &line, inner.width);
         }
     }
 }
 
-fn format_timestamp(timestamp: i64) -> String {
-    use std::time::{Duration, UNIX_EPOCH};
-
-    let datetime = UNIX_EPOCH + Duration::from_secs(timestamp as u64);
-    let secs = datetime
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs();
-
-    let days = secs / 86400;
-    let time_secs = secs % 86400;
-    let hours = time_secs / 3600;
-    let minutes = (time_secs % 3600) / 60;
-    let seconds = time_secs % 60;
-
-    let mut year = 1970u64;
-    let mut remaining_days = days;
-
-    loop {
-        let days_in_year = if is_leap_year(year) { 366 } else { 365 };
-        if remaining_days < days_in_year {
-            break;
+/// Split a line into rows at most `width` columns wide, keeping each character's style
+fn wrap_line<'a>(line: &Line<'a>, width: usize) -> Vec<Line<'a>> {
+    let mut rows = vec![Vec::new()];
+    let mut row_width = 0;
+    for grapheme in line.styled_graphemes(Style::default()) {
+        let grapheme_width = Span::raw(grapheme.symbol).width();
+        if row_width + grapheme_width > width && row_width > 0 {
+            rows.push(Vec::new());
+            row_width = 0;
         }
-        remaining_days -= days_in_year;
-        year += 1;
-    }
-
-    let days_in_months: [u64; 12] = if is_leap_year(year) {
-        [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    } else {
-        [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    };
+        row_width += grapheme_width;
 
-    let mut month = 1u64;
-    for days_in_month in days_in_months {
-        if remaining_days < days_in_month {
-            break;
+        let spans: &mut Vec<Span<'a>> = rows.last_mut().expect("rows is never empty");
+        match spans.last_mut() {
+            Some(span) if span.style == grapheme.style => {
+                span.content.to_mut().push_str(grapheme.symbol)
+            }
+            _ => spans.push(Span::styled(grapheme.symbol.to_string(), grapheme.style)),
         }
-        remaining_days -= days_in_month;
-        month += 1;
     }
-
-    let day = remaining_days + 1;
-
-    format!(
-        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
-        year, month, day, hours, minutes, seconds
-    )
+    rows.into_iter().map(Line::from).collect()
 }
 
-fn is_leap_year(year: u64) -> bool {
-    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DiffConfig;
+    use crate::domain::CommitHash;
+
+    #[test]
+    fn default_header_renders_trailers_after_the_message() {
+        let signature = Signature {
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+            raw_name: None,
+            timestamp: 1_700_000_000,
+            offset_minutes: 0,
+        };
+        let info = CommitInfo {
+            hash: CommitHash::new("abcdef0123"),
+            parents: vec![CommitHash::new("1234567890")],
+            author: signature.clone(),
+            committer: signature,
+            message: "Fix parser\n\nReviewed-by: Bob\n".to_string(),
+            refs: vec![],
+            trailers: vec![("Reviewed-by".to_string(), "Bob".to_string())],
+        };
+        let config = DiffConfig::default();
+        let theme = Theme::dark();
+        let view = DiffView::new(&[], Some(&info), &theme).with_header(&config.header);
+
+        let lines: Vec<String> = view.header_lines().iter().map(|l| l.to_string()).collect();
+        let subject = lines
+            .iter()
+            .position(|l| l.ends_with("Fix parser"))
+            .unwrap();
+        let trailer = lines
+            .iter()
+            .rposition(|l| l.starts_with("Reviewed-by:") && l.ends_with("Bob"))
+            .unwrap();
+        assert!(subject < trailer);
+        assert_eq!(lines[0], "commit abcdef0123");
+    }
 }
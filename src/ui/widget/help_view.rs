@@ -1,16 +1,22 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, StatefulWidget, Widget},
 };
 
-use crate::config::{KeymapConfig, key_binding_to_string};
-use crate::ui::action::{BlameAction, DiffAction, GlobalAction};
+use std::collections::HashMap;
+
+use crate::config::{KeySequence, KeymapConfig, UserCommand, key_sequence_to_string};
+use crate::ui::action::{
+    BlameAction, DiffAction, GlobalAction, IssuesAction, MarksAction, SessionsAction, StatsAction,
+};
+use crate::ui::theme::Theme;
 
 pub struct HelpView<'a> {
     keymap: &'a KeymapConfig,
+    theme: &'a Theme,
+    commands: Option<&'a HashMap<String, UserCommand>>,
 }
 
 pub struct HelpViewState {
@@ -19,34 +25,39 @@ pub struct HelpViewState {
 }
 
 impl<'a> HelpView<'a> {
-    pub fn new(keymap: &'a KeymapConfig) -> Self {
-        Self { keymap }
+    pub fn new(keymap: &'a KeymapConfig, theme: &'a Theme) -> Self {
+        Self {
+            keymap,
+            theme,
+            commands: None,
+        }
+    }
+
+    /// Describe the user commands bound under `[keymap.commands]`
+    pub fn with_commands(mut self, commands: &'a HashMap<String, UserCommand>) -> Self {
+        self.commands = Some(commands);
+        self
     }
 
     pub fn line_count(&self) -> usize {
         self.help_lines().len()
     }
 
-    fn format_keys(&self, keys: Vec<&crate::config::KeyBinding>) -> String {
+    fn format_keys(&self, keys: Vec<&KeySequence>) -> String {
         if keys.is_empty() {
             return "-".to_string();
         }
         keys.iter()
-            .map(|k| key_binding_to_string(k))
+            .map(|k| key_sequence_to_string(k))
             .collect::<Vec<_>>()
             .join(" / ")
     }
 
     fn help_lines(&self) -> Vec<Line<'a>> {
         let mut lines = vec![
-            Line::from(Span::styled(
-                "Keybindings",
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            )),
+            Line::from(Span::styled("Keybindings", self.theme.help_title)),
             Line::from(""),
-            Line::from(Span::styled("Blame Mode", Style::default().fg(Color::Blue))),
+            Line::from(Span::styled("Blame Mode", self.theme.help_section)),
         ];
 
         // Blame mode keybindings
@@ -59,9 +70,37 @@ impl<'a> HelpView<'a> {
             (BlameAction::CursorPageUp, "Page up"),
             (BlameAction::CursorTop, "Go to top"),
             (BlameAction::CursorBottom, "Go to bottom"),
+            (BlameAction::NextHunk, "Next hunk"),
+            (BlameAction::PreviousHunk, "Previous hunk"),
             (BlameAction::DrillDown, "Drill down (blame at parent)"),
             (BlameAction::GoBack, "Go back"),
             (BlameAction::ShowDiff, "Show diff"),
+            (BlameAction::ShowStats, "Ownership statistics"),
+            (BlameAction::ShowSessions, "Saved sessions"),
+            (BlameAction::SetMark, "Set mark {a-z}"),
+            (BlameAction::JumpToMark, "Jump to mark {a-z}"),
+            (BlameAction::ShowMarks, "List marks"),
+            (BlameAction::OpenInEditor, "Open line in editor"),
+            (BlameAction::ExportTrail, "Export trail report"),
+            (BlameAction::ShowIssues, "List issues of the line's commit"),
+            (BlameAction::HighlightIssue, "Highlight lines of an issue"),
+            (BlameAction::YankShortHash, "Copy short hash"),
+            (BlameAction::YankHash, "Copy full hash"),
+            (BlameAction::YankLine, "Copy line"),
+            (BlameAction::YankSubject, "Copy commit subject"),
+            (BlameAction::YankPermalink, "Copy commit permalink"),
+            (BlameAction::YankLineLink, "Copy line permalink"),
+            (BlameAction::YankHunkLink, "Copy hunk permalink"),
+            (BlameAction::OpenLineLink, "Open line in browser"),
+            (BlameAction::OpenHunkLink, "Open hunk in browser"),
+            (
+                BlameAction::OpenPullRequest,
+                "Open pull request of the line's commit",
+            ),
+            (BlameAction::ToggleAuthorSubject, "Toggle author/subject"),
+            (BlameAction::CycleColoring, "Cycle line colouring"),
+            (BlameAction::ToggleAuthorLegend, "Toggle author legend"),
+            (BlameAction::CycleBlockStyle, "Cycle hunk grouping"),
         ];
 
         for (action, desc) in blame_bindings {
@@ -72,7 +111,7 @@ impl<'a> HelpView<'a> {
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
             "Diff Mode",
-            Style::default().fg(Color::Blue),
+            self.theme.help_section,
         )));
 
         // Diff mode keybindings
@@ -86,7 +125,13 @@ impl<'a> HelpView<'a> {
             (DiffAction::ScrollTop, "Scroll to top"),
             (DiffAction::ScrollBottom, "Scroll to bottom"),
             (DiffAction::Close, "Close diff"),
-            (DiffAction::OpenInGitHub, "Open in GitHub"),
+            (DiffAction::OpenInBrowser, "Open commit in browser"),
+            (DiffAction::OpenPullRequest, "Open pull request"),
+            (DiffAction::ShowIssues, "List issues"),
+            (DiffAction::YankShortHash, "Copy short hash"),
+            (DiffAction::YankHash, "Copy full hash"),
+            (DiffAction::YankSubject, "Copy commit subject"),
+            (DiffAction::YankPermalink, "Copy commit permalink"),
         ];
 
         for (action, desc) in diff_bindings {
@@ -96,13 +141,114 @@ impl<'a> HelpView<'a> {
 
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
-            "Global",
-            Style::default().fg(Color::Blue),
+            "Ownership Statistics",
+            self.theme.help_section,
         )));
 
+        // Stats mode keybindings
+        let stats_bindings = [
+            (StatsAction::CursorDown, "Cursor down"),
+            (StatsAction::CursorUp, "Cursor up"),
+            (StatsAction::Select, "Highlight author/commit lines"),
+            (StatsAction::Close, "Close"),
+        ];
+
+        for (action, desc) in stats_bindings {
+            let keys = self.format_keys(self.keymap.keys_for_stats(action));
+            lines.push(Line::from(format!("  {:15} {}", keys, desc)));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Saved Sessions",
+            self.theme.help_section,
+        )));
+
+        // Sessions mode keybindings
+        let sessions_bindings = [
+            (SessionsAction::CursorDown, "Cursor down"),
+            (SessionsAction::CursorUp, "Cursor up"),
+            (SessionsAction::Select, "Restore session"),
+            (SessionsAction::Delete, "Delete session"),
+            (SessionsAction::Close, "Close"),
+        ];
+
+        for (action, desc) in sessions_bindings {
+            let keys = self.format_keys(self.keymap.keys_for_sessions(action));
+            lines.push(Line::from(format!("  {:15} {}", keys, desc)));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Marks", self.theme.help_section)));
+
+        // Marks mode keybindings
+        let marks_bindings = [
+            (MarksAction::CursorDown, "Cursor down"),
+            (MarksAction::CursorUp, "Cursor up"),
+            (MarksAction::Select, "Jump to mark"),
+            (MarksAction::Delete, "Delete mark"),
+            (MarksAction::Close, "Close"),
+        ];
+
+        for (action, desc) in marks_bindings {
+            let keys = self.format_keys(self.keymap.keys_for_marks(action));
+            lines.push(Line::from(format!("  {:15} {}", keys, desc)));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Issues", self.theme.help_section)));
+
+        // Issues mode keybindings
+        let issues_bindings = [
+            (IssuesAction::CursorDown, "Cursor down"),
+            (IssuesAction::CursorUp, "Cursor up"),
+            (IssuesAction::Select, "Open issue"),
+            (IssuesAction::Highlight, "Highlight lines of the issue"),
+            (IssuesAction::Close, "Close"),
+        ];
+
+        for (action, desc) in issues_bindings {
+            let keys = self.format_keys(self.keymap.keys_for_issues(action));
+            lines.push(Line::from(format!("  {:15} {}", keys, desc)));
+        }
+
+        // User commands, listed only when some are bound
+        let bindings = &self.keymap.commands;
+        let mut commands: Vec<(&str, &KeySequence, &String)> = bindings
+            .blame
+            .iter()
+            .map(|(key, name)| ("blame", key, name))
+            .chain(bindings.diff.iter().map(|(key, name)| ("diff", key, name)))
+            .collect();
+        commands.sort_by_key(|&(mode, key, name)| (mode, name, key_sequence_to_string(key)));
+        if !commands.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Commands",
+                self.theme.help_section,
+            )));
+            for (mode, key, name) in commands {
+                let description = self
+                    .commands
+                    .and_then(|c| c.get(name))
+                    .and_then(|c| c.description.as_deref())
+                    .unwrap_or(name);
+                lines.push(Line::from(format!(
+                    "  {:15} {} ({})",
+                    key_sequence_to_string(key),
+                    description,
+                    mode
+                )));
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Global", self.theme.help_section)));
+
         // Global keybindings
         let global_bindings = [
             (GlobalAction::ShowHelp, "Show this help"),
+            (GlobalAction::CommandLine, "Run a command by name"),
             (GlobalAction::Quit, "Quit"),
         ];
 
@@ -131,7 +277,8 @@ impl<'a> StatefulWidget for HelpView<'a> {
 
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(" Help (press q to close) ");
+            .border_style(self.theme.border)
+            .title(Span::styled(" Help (press q to close) ", self.theme.title));
 
         let inner = block.inner(popup_area);
         block.render(popup_area, buf);
@@ -161,16 +308,15 @@ impl<'a> StatefulWidget for HelpView<'a> {
             let is_selected = line_index == state.selected_line;
 
             if is_selected {
-                // Fill entire line with REVERSED background first (for empty lines)
-                let reversed_style = Style::default().add_modifier(Modifier::REVERSED);
+                // Fill entire line with the selection style first (for empty lines)
                 for x in inner.x..inner.x + inner.width {
-                    buf[(x, y)].set_style(reversed_style);
+                    buf[(x, y)].set_style(self.theme.selection);
                 }
 
-                // Apply REVERSED modifier for selected line content
+                // Apply the selection style to the selected line content
                 let mut styled_line = line.clone();
                 for span in &mut styled_line.spans {
-                    span.style = span.style.add_modifier(Modifier::REVERSED);
+                    span.style = span.style.patch(self.theme.selection);
                 }
                 buf.set_line(inner.x, y, &styled_line, inner.width);
             } else {
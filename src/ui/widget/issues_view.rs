@@ -0,0 +1,51 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Widget,
+};
+
+use super::list_popup::ListPopup;
+use crate::issues::IssueRef;
+use crate::ui::theme::Theme;
+
+/// Overlay listing the issue references of a commit
+pub struct IssuesView<'a> {
+    issues: &'a [IssueRef],
+    theme: &'a Theme,
+    selected: usize,
+}
+
+impl<'a> IssuesView<'a> {
+    pub fn new(issues: &'a [IssueRef], theme: &'a Theme, selected: usize) -> Self {
+        Self {
+            issues,
+            theme,
+            selected,
+        }
+    }
+}
+
+impl Widget for IssuesView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let items = self
+            .issues
+            .iter()
+            .map(|issue| {
+                Line::from(vec![
+                    Span::styled(format!(" {:14}", issue.id), self.theme.diff_issue),
+                    Span::raw(issue.url.as_str()),
+                ])
+            })
+            .collect();
+
+        ListPopup::new(
+            " Issues (Enter to open, h to highlight lines, q to close) ",
+            items,
+            self.theme,
+            self.selected,
+        )
+        .with_size(80, 12)
+        .render(area, buf);
+    }
+}
@@ -0,0 +1,94 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Widget},
+};
+
+use crate::ui::theme::Theme;
+
+/// Centred popup with one line per item, scrolled to keep the selected item visible
+pub struct ListPopup<'a> {
+    title: &'a str,
+    items: Vec<Line<'a>>,
+    theme: &'a Theme,
+    selected: usize,
+    width: u16,
+    height: u16,
+    /// Shown in place of the list when there are no items
+    empty: &'a str,
+}
+
+impl<'a> ListPopup<'a> {
+    pub fn new(title: &'a str, items: Vec<Line<'a>>, theme: &'a Theme, selected: usize) -> Self {
+        Self {
+            title,
+            items,
+            theme,
+            selected,
+            width: 80,
+            height: 20,
+            empty: "",
+        }
+    }
+
+    /// Largest size, shrunk to fit the terminal
+    pub fn with_size(mut self, width: u16, height: u16) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn with_empty(mut self, empty: &'a str) -> Self {
+        self.empty = empty;
+        self
+    }
+}
+
+impl Widget for ListPopup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Center the popup
+        let width = self.width.min(area.width.saturating_sub(4));
+        let height = self.height.min(area.height.saturating_sub(4));
+        let x = area.x + (area.width - width) / 2;
+        let y = area.y + (area.height - height) / 2;
+        let popup_area = Rect::new(x, y, width, height);
+
+        Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.theme.border)
+            .title(Span::styled(self.title, self.theme.title));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        if self.items.is_empty() {
+            buf.set_line(inner.x, inner.y, &Line::from(self.empty), inner.width);
+            return;
+        }
+
+        // Keep the selection visible
+        let visible_lines = inner.height as usize;
+        let start = (self.selected + 1).saturating_sub(visible_lines);
+
+        for (i, mut line) in self
+            .items
+            .into_iter()
+            .enumerate()
+            .skip(start)
+            .take(visible_lines)
+        {
+            let y = inner.y + (i - start) as u16;
+            if i == self.selected {
+                for x in inner.x..inner.x + inner.width {
+                    buf[(x, y)].set_style(self.theme.selection);
+                }
+                for span in &mut line.spans {
+                    span.style = span.style.patch(self.theme.selection);
+                }
+            }
+            buf.set_line(inner.x, y, &line, inner.width);
+        }
+    }
+}
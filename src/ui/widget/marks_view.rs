@@ -0,0 +1,57 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Widget,
+};
+
+use super::list_popup::ListPopup;
+use crate::domain::Mark;
+use crate::ui::theme::Theme;
+
+/// Overlay listing the repository's marks
+pub struct MarksView<'a> {
+    marks: &'a [Mark],
+    theme: &'a Theme,
+    selected: usize,
+}
+
+impl<'a> MarksView<'a> {
+    pub fn new(marks: &'a [Mark], theme: &'a Theme, selected: usize) -> Self {
+        Self {
+            marks,
+            theme,
+            selected,
+        }
+    }
+}
+
+impl Widget for MarksView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let items = self
+            .marks
+            .iter()
+            .map(|mark| {
+                let revision = &mark.revision[..7.min(mark.revision.len())];
+                Line::from(vec![
+                    Span::styled(format!(" {}  ", mark.name), self.theme.help_section),
+                    Span::styled(
+                        format!("{}:{}", mark.path.display(), mark.line),
+                        self.theme.title,
+                    ),
+                    Span::styled(format!(" @{}", revision), self.theme.hash_new),
+                ])
+            })
+            .collect();
+
+        ListPopup::new(
+            " Marks (Enter to jump, d to delete, q to close) ",
+            items,
+            self.theme,
+            self.selected,
+        )
+        .with_size(72, 20)
+        .with_empty("No marks; press m{a-z} on a line to set one")
+        .render(area, buf);
+    }
+}
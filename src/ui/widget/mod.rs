@@ -1,9 +1,22 @@
+mod author_legend;
 mod blame_view;
+mod command_palette;
 mod diff_view;
 mod help_view;
+mod issues_view;
+mod list_popup;
+mod marks_view;
+mod sessions_view;
+mod stats_view;
 mod status_bar;
 
+pub use author_legend::AuthorLegend;
 pub use blame_view::{BlameView, BlameViewState};
+pub use command_palette::CommandPalette;
 pub use diff_view::{DiffView, DiffViewState};
 pub use help_view::{HelpView, HelpViewState};
+pub use issues_view::IssuesView;
+pub use marks_view::MarksView;
+pub use sessions_view::SessionsView;
+pub use stats_view::{StatsView, StatsViewState};
 pub use status_bar::StatusBar;
@@ -0,0 +1,62 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Widget,
+};
+
+use super::list_popup::ListPopup;
+use crate::domain::Session;
+use crate::domain::date::relative_age;
+use crate::ui::theme::Theme;
+
+/// Overlay listing saved sessions of the current repository
+pub struct SessionsView<'a> {
+    sessions: &'a [Session],
+    theme: &'a Theme,
+    now: i64,
+    selected: usize,
+}
+
+impl<'a> SessionsView<'a> {
+    pub fn new(sessions: &'a [Session], theme: &'a Theme, now: i64, selected: usize) -> Self {
+        Self {
+            sessions,
+            theme,
+            now,
+            selected,
+        }
+    }
+}
+
+impl Widget for SessionsView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let items = self
+            .sessions
+            .iter()
+            .map(|session| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:14}", relative_age(session.saved_at, self.now)),
+                        self.theme.age,
+                    ),
+                    Span::styled(session.file.to_string_lossy().to_string(), self.theme.title),
+                    Span::raw("  "),
+                    Span::styled(
+                        format!("[{}]", session.hash_chain()),
+                        self.theme.status_chain,
+                    ),
+                ])
+            })
+            .collect();
+
+        ListPopup::new(
+            " Sessions (Enter to restore, d to delete, q to close) ",
+            items,
+            self.theme,
+            self.selected,
+        )
+        .with_empty("No saved sessions")
+        .render(area, buf);
+    }
+}
@@ -0,0 +1,239 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, StatefulWidget, Widget},
+};
+
+use crate::domain::date::{DateFormat, DateStyle};
+use crate::domain::{BlameFrame, Owner, OwnershipStats};
+use crate::ui::theme::{AuthorSlots, Theme};
+
+/// Widest bar in the age histogram
+const HISTOGRAM_WIDTH: usize = 24;
+
+/// Overlay summarising who owns the frame's lines and how old they are
+pub struct StatsView<'a> {
+    stats: &'a OwnershipStats,
+    frame: &'a BlameFrame,
+    theme: &'a Theme,
+    now: i64,
+    /// Index into `OwnershipStats::owners`
+    selected: usize,
+    highlighted: Option<&'a Owner>,
+    date_format: Option<&'a DateFormat>,
+    show_raw_identity: bool,
+    author_slots: Option<&'a AuthorSlots>,
+}
+
+pub struct StatsViewState {
+    pub scroll_offset: usize,
+}
+
+impl<'a> StatsView<'a> {
+    pub fn new(
+        stats: &'a OwnershipStats,
+        frame: &'a BlameFrame,
+        theme: &'a Theme,
+        now: i64,
+        selected: usize,
+    ) -> Self {
+        Self {
+            stats,
+            frame,
+            theme,
+            now,
+            selected,
+            highlighted: None,
+            date_format: None,
+            show_raw_identity: false,
+            author_slots: None,
+        }
+    }
+
+    /// Mark the owner whose lines are currently highlighted
+    pub fn with_highlighted(mut self, highlighted: Option<&'a Owner>) -> Self {
+        self.highlighted = highlighted;
+        self
+    }
+
+    pub fn with_date_format(mut self, date_format: &'a DateFormat) -> Self {
+        self.date_format = Some(date_format);
+        self
+    }
+
+    pub fn with_raw_identity(mut self, show_raw_identity: bool) -> Self {
+        self.show_raw_identity = show_raw_identity;
+        self
+    }
+
+    /// Session-wide author colours, matching the blame view's
+    pub fn with_author_slots(mut self, author_slots: &'a AuthorSlots) -> Self {
+        self.author_slots = Some(author_slots);
+        self
+    }
+
+    /// Overlay lines, and the line index of each selectable owner
+    fn lines(&self) -> (Vec<Line<'a>>, Vec<usize>) {
+        let stats = self.stats;
+        let theme = self.theme;
+        let mut lines = Vec::new();
+        let mut owner_lines = Vec::new();
+        let default_date = DateFormat::new(DateStyle::Relative);
+        let date_format = self.date_format.unwrap_or(&default_date);
+        let no_slots = AuthorSlots::default();
+        let author_slots = self.author_slots.unwrap_or(&no_slots);
+
+        lines.push(Line::from(format!(
+            "{} lines, {} authors, {} commits",
+            stats.total_lines,
+            stats.authors.len(),
+            stats.commits.len()
+        )));
+        for (label, index) in [("Oldest", stats.oldest), ("Newest", stats.newest)] {
+            if let Some(entry) = index.and_then(|i| self.frame.entries.get(i)) {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{:8}", label), theme.help_section),
+                    Span::raw(format!("line {} ", entry.line_number)),
+                    Span::styled(entry.commit_hash.short().to_string(), theme.hash_new),
+                    Span::raw(format!(
+                        " {}, {}",
+                        date_format.format(entry.timestamp, entry.offset_minutes, self.now),
+                        entry.author_label(self.show_raw_identity)
+                    )),
+                ]));
+            }
+        }
+
+        let marker = |owner: &Owner| {
+            if self.highlighted == Some(owner) {
+                "* "
+            } else {
+                "  "
+            }
+        };
+
+        lines.push(Line::raw(""));
+        lines.push(Line::from(Span::styled("Authors", theme.help_section)));
+        let name_width = stats
+            .authors
+            .iter()
+            .map(|a| a.author_label(self.show_raw_identity).chars().count())
+            .max()
+            .unwrap_or(0)
+            .min(24);
+        for author in &stats.authors {
+            let owner = Owner::Author(author.key.clone());
+            let name: String = author
+                .author_label(self.show_raw_identity)
+                .chars()
+                .take(name_width)
+                .collect();
+            owner_lines.push(lines.len());
+            lines.push(Line::from(vec![
+                Span::raw(marker(&owner)),
+                Span::styled(
+                    format!("{:name_width$}", name),
+                    theme.author_color(&author.key, author_slots),
+                ),
+                Span::raw(format!(
+                    " {:>5} {:>5.1}%",
+                    author.lines,
+                    stats.percent(author.lines)
+                )),
+            ]));
+        }
+
+        lines.push(Line::raw(""));
+        lines.push(Line::from(Span::styled("Commits", theme.help_section)));
+        for commit in &stats.commits {
+            let owner = Owner::Commit(commit.hash.clone());
+            let author: String = commit.author.chars().take(12).collect();
+            owner_lines.push(lines.len());
+            lines.push(Line::from(vec![
+                Span::raw(marker(&owner)),
+                Span::styled(commit.hash.short().to_string(), theme.hash_new),
+                Span::styled(format!(" {:12}", author), theme.author),
+                Span::raw(format!(
+                    " {:>5} {:>5.1}%  {}",
+                    commit.lines,
+                    stats.percent(commit.lines),
+                    commit.summary
+                )),
+            ]));
+        }
+
+        lines.push(Line::raw(""));
+        lines.push(Line::from(Span::styled("Age", theme.help_section)));
+        let largest = stats.ages.iter().map(|b| b.lines).max().unwrap_or(0).max(1);
+        for bucket in &stats.ages {
+            let bar = (bucket.lines * HISTOGRAM_WIDTH).div_ceil(largest);
+            lines.push(Line::from(vec![
+                Span::raw(format!("  {:11} ", bucket.label)),
+                Span::styled("█".repeat(bar), theme.age),
+                Span::raw(format!(" {}", bucket.lines)),
+            ]));
+        }
+
+        (lines, owner_lines)
+    }
+}
+
+impl<'a> StatefulWidget for StatsView<'a> {
+    type State = StatsViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        // Center the popup
+        let width = 72.min(area.width.saturating_sub(4));
+        let height = 30.min(area.height.saturating_sub(4));
+        let x = area.x + (area.width - width) / 2;
+        let y = area.y + (area.height - height) / 2;
+        let popup_area = Rect::new(x, y, width, height);
+
+        Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.theme.border)
+            .title(Span::styled(
+                " Ownership (Enter to highlight, q to close) ",
+                self.theme.title,
+            ));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        let (lines, owner_lines) = self.lines();
+        let selected_line = owner_lines.get(self.selected).copied();
+        let visible_lines = inner.height as usize;
+
+        // Keep the selected owner visible, showing the summary above the first one
+        if let Some(selected_line) = selected_line {
+            if self.selected == 0 && selected_line < visible_lines {
+                state.scroll_offset = 0;
+            } else if selected_line < state.scroll_offset {
+                state.scroll_offset = selected_line;
+            } else if selected_line >= state.scroll_offset + visible_lines {
+                state.scroll_offset = selected_line - visible_lines + 1;
+            }
+        }
+
+        let start = state.scroll_offset.min(lines.len());
+        let end = (start + visible_lines).min(lines.len());
+
+        for (i, line) in lines[start..end].iter().enumerate() {
+            let y = inner.y + i as u16;
+            if Some(start + i) == selected_line {
+                for x in inner.x..inner.x + inner.width {
+                    buf[(x, y)].set_style(self.theme.selection);
+                }
+                let mut line = line.clone();
+                for span in &mut line.spans {
+                    span.style = span.style.patch(self.theme.selection);
+                }
+                buf.set_line(inner.x, y, &line, inner.width);
+            } else {
+                buf.set_line(inner.x, y, line, inner.width);
+            }
+        }
+    }
+}
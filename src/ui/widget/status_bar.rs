@@ -1,23 +1,29 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::Widget,
 };
 
 use crate::ui::mode::Mode;
+use crate::ui::prompt::Prompt;
+use crate::ui::theme::Theme;
 
-pub struct StatusBar {
+pub struct StatusBar<'a> {
+    theme: &'a Theme,
     mode: Mode,
     file_path: String,
     position: String,
     hash_chain: Option<String>,
     message: Option<String>,
+    legend: Vec<Span<'a>>,
+    prompt: Option<&'a Prompt>,
+    pending: Option<String>,
 }
 
-impl StatusBar {
+impl<'a> StatusBar<'a> {
     pub fn new(
+        theme: &'a Theme,
         mode: Mode,
         file_path: &str,
         current_line: usize,
@@ -25,11 +31,15 @@ impl StatusBar {
         hash_chain: Option<String>,
     ) -> Self {
         Self {
+            theme,
             mode,
             file_path: file_path.to_string(),
             position: format!("{}/{}", current_line + 1, total_lines),
             hash_chain,
             message: None,
+            legend: Vec::new(),
+            prompt: None,
+            pending: None,
         }
     }
 
@@ -37,26 +47,43 @@ impl StatusBar {
         self.message = message.map(|s| s.to_string());
         self
     }
+
+    /// Replace the file path and message with a text prompt
+    pub fn with_prompt(mut self, prompt: Option<&'a Prompt>) -> Self {
+        self.prompt = prompt;
+        self
+    }
+
+    /// Count and keys of a binding still being typed, shown left of the legend
+    pub fn with_pending(mut self, pending: Option<String>) -> Self {
+        self.pending = pending;
+        self
+    }
+
+    /// Right-aligned legend shown next to the position
+    pub fn with_legend(mut self, legend: Vec<Span<'a>>) -> Self {
+        self.legend = legend;
+        self
+    }
 }
 
-impl Widget for StatusBar {
+impl Widget for StatusBar<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         // Fill background
-        let style = Style::default().bg(Color::DarkGray).fg(Color::White);
+        let style = self.theme.status_bar;
         for x in area.x..area.x + area.width {
             buf[(x, area.y)].set_style(style);
         }
 
         // Mode indicator
-        let mode_style = Style::default()
-            .bg(Color::Blue)
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD);
-        let mode_span = Span::styled(format!(" {} ", self.mode.name()), mode_style);
+        let mode_span = Span::styled(format!(" {} ", self.mode.name()), self.theme.status_mode);
 
         // Hash chain (if drilling down)
         let chain_span = if let Some(ref chain) = self.hash_chain {
-            Span::styled(format!(" [{}] ", chain), style.fg(Color::Yellow))
+            Span::styled(
+                format!(" [{}] ", chain),
+                style.patch(self.theme.status_chain),
+            )
         } else {
             Span::raw("")
         };
@@ -66,11 +93,29 @@ impl Widget for StatusBar {
 
         // Message (if any)
         let message_span = if let Some(ref msg) = self.message {
-            Span::styled(format!(" {} ", msg), style.fg(Color::Yellow))
+            Span::styled(format!(" {} ", msg), style.patch(self.theme.status_message))
         } else {
             Span::raw("")
         };
 
+        if let Some(prompt) = self.prompt {
+            let mut spans = vec![
+                mode_span,
+                Span::styled(format!(" {} ", prompt.kind.label()), style),
+                Span::styled(prompt.input.clone(), style.patch(self.theme.status_message)),
+                Span::styled("█", style),
+            ];
+            if let Some(error) = &prompt.error {
+                spans.push(Span::styled(
+                    format!("  {}", error),
+                    style.patch(self.theme.status_message),
+                ));
+            }
+            let line = Line::from(spans);
+            buf.set_line(area.x, area.y, &line, area.width);
+            return;
+        }
+
         // Position (right aligned)
         let pos_span = Span::styled(format!(" {} ", self.position), style);
 
@@ -79,8 +124,30 @@ impl Widget for StatusBar {
 
         buf.set_line(area.x, area.y, &left, area.width);
 
-        // Right align position
+        // Right align position, with the legend to its left
         let right_x = area.x + area.width.saturating_sub(self.position.len() as u16 + 2);
         buf.set_line(right_x, area.y, &right, area.width);
+
+        let legend = Line::from(self.legend);
+        let legend_x = right_x.saturating_sub(legend.width() as u16);
+        if legend_x > area.x {
+            buf.set_line(legend_x, area.y, &legend, right_x - legend_x);
+        }
+
+        if let Some(pending) = self.pending {
+            let pending = Span::styled(
+                format!(" {} ", pending),
+                style.patch(self.theme.status_chain),
+            );
+            let pending_x = legend_x.saturating_sub(pending.width() as u16);
+            if pending_x > area.x {
+                buf.set_line(
+                    pending_x,
+                    area.y,
+                    &Line::from(pending),
+                    legend_x - pending_x,
+                );
+            }
+        }
     }
 }